@@ -0,0 +1,209 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T
+}
+
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Point { x, y }
+    }
+}
+
+impl<T: Copy + PartialOrd + Sub<Output = T> + Add<Output = T>> Point<T> {
+    pub fn manhattan(self, other: Self) -> T {
+        let abs_diff = |a: T, b: T| if a > b { a - b } else { b - a };
+
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y)
+    }
+}
+
+impl Point<usize> {
+    pub fn checked_offset<D: Into<Point<isize>>>(self, delta: D) -> Option<Self> {
+        let delta = delta.into();
+
+        Some(Point::new(self.x.checked_add_signed(delta.x)?, self.y.checked_add_signed(delta.y)?))
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Point::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Point::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Point<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Point::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl<T> From<(T, T)> for Point<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Point::new(x, y)
+    }
+}
+
+// y grows downwards, as in the puzzle inputs
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Direction4 {
+    UP,
+    RIGHT,
+    DOWN,
+    LEFT
+}
+
+impl Direction4 {
+    pub const ALL: [Direction4; 4] = [Direction4::UP, Direction4::RIGHT, Direction4::DOWN, Direction4::LEFT];
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        self == Direction4::LEFT || self == Direction4::RIGHT
+    }
+
+    pub fn is_vertical(self) -> bool {
+        !self.is_horizontal()
+    }
+
+    pub fn delta(self) -> Point<isize> {
+        match self {
+            Direction4::UP => Point::new(0, -1),
+            Direction4::RIGHT => Point::new(1, 0),
+            Direction4::DOWN => Point::new(0, 1),
+            Direction4::LEFT => Point::new(-1, 0)
+        }
+    }
+}
+
+impl From<Direction4> for Point<isize> {
+    fn from(direction: Direction4) -> Self {
+        direction.delta()
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Direction8 {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW
+}
+
+impl Direction8 {
+    pub const ALL: [Direction8; 8] = [
+        Direction8::N, Direction8::NE, Direction8::E, Direction8::SE,
+        Direction8::S, Direction8::SW, Direction8::W, Direction8::NW
+    ];
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    pub fn delta(self) -> Point<isize> {
+        match self {
+            Direction8::N => Point::new(0, -1),
+            Direction8::NE => Point::new(1, -1),
+            Direction8::E => Point::new(1, 0),
+            Direction8::SE => Point::new(1, 1),
+            Direction8::S => Point::new(0, 1),
+            Direction8::SW => Point::new(-1, 1),
+            Direction8::W => Point::new(-1, 0),
+            Direction8::NW => Point::new(-1, -1)
+        }
+    }
+}
+
+impl From<Direction8> for Point<isize> {
+    fn from(direction: Direction8) -> Self {
+        direction.delta()
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(direction: Direction4) -> Self {
+        Direction8::ALL[direction as usize * 2]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::geometry::*;
+
+    #[test]
+    fn test_manhattan() {
+        assert_eq!(Point::new(1, 6).manhattan(Point::new(5, 11)), 9);
+        assert_eq!(Point::new(4usize, 0).manhattan(Point::new(0, 3)), 7);
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        assert_eq!(Point::new(1, 2) + Point::new(3, -4), Point::new(4, -2));
+        assert_eq!(Point::new(1, 2) - Point::new(3, -4), Point::new(-2, 6));
+        assert_eq!(Direction4::LEFT.delta() * 5, Point::new(-5, 0));
+        assert_eq!(-Direction8::NE.delta(), Direction8::SW.delta());
+    }
+
+    #[test]
+    fn test_checked_offset() {
+        assert_eq!(Point::new(0usize, 1).checked_offset(Direction4::DOWN), Some(Point::new(0, 2)));
+        assert_eq!(Point::new(0usize, 1).checked_offset(Direction4::LEFT), None);
+        assert_eq!(Point::new(2usize, 2).checked_offset((-2, -1)), Some(Point::new(0, 1)));
+    }
+
+    #[test]
+    fn test_turning() {
+        assert_eq!(Direction4::UP.turn_right(), Direction4::RIGHT);
+        assert_eq!(Direction4::UP.turn_left(), Direction4::LEFT);
+        assert_eq!(Direction4::LEFT.opposite(), Direction4::RIGHT);
+        assert!(Direction4::ALL.iter().all(|d| d.turn_right().turn_left() == *d && d.opposite().opposite() == *d));
+        assert_eq!(Direction8::NW.turn_right(), Direction8::N);
+        assert_eq!(Direction8::N.turn_left(), Direction8::NW);
+        assert_eq!(Direction8::SE.opposite(), Direction8::NW);
+        assert_eq!(Direction8::from(Direction4::LEFT), Direction8::W);
+    }
+}
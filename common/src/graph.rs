@@ -0,0 +1,337 @@
+use std::collections::{HashMap, VecDeque};
+use std::fmt::Display;
+use std::hash::Hash;
+
+// an undirected multigraph whose nodes are interned to ids `0..node_count()` in insertion order;
+// edge ids stay valid when other edges are removed
+#[derive(Clone, Debug)]
+pub struct Graph<N, E = ()> {
+    nodes: Vec<N>,
+    ids: HashMap<N, usize>,
+    edges: Vec<Option<(usize, usize, E)>>,
+    adjacency: Vec<Vec<(usize, usize)>>
+}
+
+// the edges crossing a cut and, for every node, whether it's on the source side
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Cut {
+    pub edges: Vec<usize>,
+    pub side: Vec<bool>
+}
+
+impl<N: Clone + Eq + Hash, E> Default for Graph<N, E> {
+    fn default() -> Self {
+        Graph { nodes: Vec::new(), ids: HashMap::new(), edges: Vec::new(), adjacency: Vec::new() }
+    }
+}
+
+impl<N: Clone + Eq + Hash, E> Graph<N, E> {
+    pub fn new() -> Self {
+        Graph::default()
+    }
+
+    pub fn add_node(&mut self, node: N) -> usize {
+        if let Some(&id) = self.ids.get(&node) {
+            return id;
+        }
+
+        self.nodes.push(node.clone());
+        self.adjacency.push(Vec::new());
+        self.ids.insert(node, self.nodes.len() - 1);
+
+        self.nodes.len() - 1
+    }
+
+    pub fn add_edge(&mut self, a: N, b: N, data: E) -> usize {
+        let (a, b) = (self.add_node(a), self.add_node(b));
+
+        self.connect(a, b, data)
+    }
+
+    pub fn connect(&mut self, a: usize, b: usize, data: E) -> usize {
+        let edge = self.edges.len();
+
+        self.edges.push(Some((a, b, data)));
+        self.adjacency[a].push((b, edge));
+        if a != b {
+            self.adjacency[b].push((a, edge));
+        }
+
+        edge
+    }
+
+    pub fn remove_edge(&mut self, edge: usize) -> Option<E> {
+        let (a, b, data) = self.edges.get_mut(edge)?.take()?;
+
+        self.adjacency[a].retain(|&(_, e)| e != edge);
+        self.adjacency[b].retain(|&(_, e)| e != edge);
+
+        Some(data)
+    }
+
+    pub fn id(&self, node: &N) -> Option<usize> {
+        self.ids.get(node).copied()
+    }
+
+    pub fn node(&self, id: usize) -> &N {
+        &self.nodes[id]
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.edges.iter().flatten().count()
+    }
+
+    pub fn edge(&self, edge: usize) -> Option<(usize, usize, &E)> {
+        self.edges.get(edge)?.as_ref().map(|(a, b, data)| (*a, *b, data))
+    }
+
+    pub fn edges(&self) -> impl Iterator<Item = (usize, usize, usize, &E)> {
+        self.edges.iter().enumerate().filter_map(|(edge, e)| e.as_ref().map(|(a, b, data)| (edge, *a, *b, data)))
+    }
+
+    pub fn find_edge(&self, a: usize, b: usize) -> Option<usize> {
+        self.adjacency[a].iter().find(|&&(n, _)| n == b).map(|&(_, edge)| edge)
+    }
+
+    // (neighbour, edge) pairs
+    pub fn neighbours(&self, id: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.adjacency[id].iter().copied()
+    }
+
+    pub fn degree(&self, id: usize) -> usize {
+        self.adjacency[id].len()
+    }
+
+    // (node, depth) pairs in the order they're reached
+    pub fn bfs(&self, start: usize) -> Vec<(usize, usize)> {
+        let mut visited = vec![false; self.nodes.len()];
+        let mut order = Vec::new();
+        let mut q = VecDeque::from([(start, 0)]);
+        visited[start] = true;
+
+        while let Some((id, depth)) = q.pop_front() {
+            order.push((id, depth));
+
+            for (next, _) in self.neighbours(id) {
+                if !visited[next] {
+                    visited[next] = true;
+                    q.push_back((next, depth + 1));
+                }
+            }
+        }
+
+        order
+    }
+
+    // nodes in preorder
+    pub fn dfs(&self, start: usize) -> Vec<usize> {
+        let mut visited = vec![false; self.nodes.len()];
+        let mut order = Vec::new();
+        let mut stack = vec![start];
+
+        while let Some(id) = stack.pop() {
+            if visited[id] {
+                continue;
+            }
+
+            visited[id] = true;
+            order.push(id);
+            stack.extend(self.adjacency[id].iter().rev().map(|&(next, _)| next).filter(|&next| !visited[next]));
+        }
+
+        order
+    }
+
+    pub fn component_of(&self, id: usize) -> Vec<usize> {
+        self.bfs(id).into_iter().map(|(id, _)| id).collect()
+    }
+
+    pub fn components(&self) -> Vec<Vec<usize>> {
+        let mut seen = vec![false; self.nodes.len()];
+        let mut components = Vec::new();
+
+        for id in 0..self.nodes.len() {
+            if !seen[id] {
+                let component = self.component_of(id);
+                component.iter().for_each(|&id| seen[id] = true);
+                components.push(component);
+            }
+        }
+
+        components
+    }
+
+    // every edge carries one unit in either direction
+    pub fn max_flow(&self, source: usize, sink: usize) -> usize {
+        self.bounded_flow(source, sink, usize::MAX).0
+    }
+
+    pub fn min_cut(&self, source: usize, sink: usize) -> Cut {
+        let (_, side) = self.bounded_flow(source, sink, usize::MAX);
+
+        self.cut(side)
+    }
+
+    // the smallest cut separating node 0 from any other node is the smallest cut of the whole graph
+    pub fn global_min_cut(&self) -> Option<Cut> {
+        let mut best: Option<(usize, Vec<bool>)> = None;
+
+        for sink in 1..self.nodes.len() {
+            let limit = best.as_ref().map_or(usize::MAX, |(flow, _)| *flow);
+            let (flow, side) = self.bounded_flow(0, sink, limit);
+
+            if flow < limit {
+                best = Some((flow, side));
+            }
+        }
+
+        best.map(|(_, side)| self.cut(side))
+    }
+
+    fn cut(&self, side: Vec<bool>) -> Cut {
+        let edges = self.edges().filter(|&(_, a, b, _)| side[a] != side[b]).map(|(edge, ..)| edge).collect();
+
+        Cut { edges, side }
+    }
+
+    // Edmonds-Karp, giving up once the flow reaches `limit`; if it doesn't,
+    // the nodes still reachable from `source` are the source side of a minimum cut
+    fn bounded_flow(&self, source: usize, sink: usize, limit: usize) -> (usize, Vec<bool>) {
+        assert_ne!(source, sink, "the source and the sink of a flow must differ");
+
+        // flow along each edge from its first node to its second
+        let mut flows = vec![0i32; self.edges.len()];
+        let mut flow = 0;
+
+        loop {
+            let mut parents = vec![None; self.nodes.len()];
+            let mut reached = vec![false; self.nodes.len()];
+            let mut q = VecDeque::from([source]);
+            reached[source] = true;
+
+            while let Some(id) = q.pop_front() {
+                for &(next, edge) in &self.adjacency[id] {
+                    let forward = self.edges[edge].as_ref().is_some_and(|&(a, _, _)| a == id);
+                    let residual = if forward { 1 - flows[edge] } else { 1 + flows[edge] };
+
+                    if !reached[next] && residual > 0 {
+                        reached[next] = true;
+                        parents[next] = Some((id, edge));
+                        q.push_back(next);
+                    }
+                }
+            }
+
+            if !reached[sink] || flow == limit {
+                return (flow, reached);
+            }
+
+            let mut id = sink;
+            while let Some((parent, edge)) = parents[id] {
+                let forward = self.edges[edge].as_ref().is_some_and(|&(a, _, _)| a == parent);
+                flows[edge] += if forward { 1 } else { -1 };
+                id = parent;
+            }
+
+            flow += 1;
+        }
+    }
+}
+
+impl<N: Clone + Eq + Hash + Display, E> Graph<N, E> {
+    // `attributes` can style each edge, e.g. with `color=red`
+    pub fn to_dot<F: Fn(usize, &E) -> Option<String>>(&self, attributes: F) -> String {
+        let mut dot = "graph {\n".to_owned();
+
+        for (edge, a, b, data) in self.edges() {
+            match attributes(edge, data) {
+                Some(attributes) => dot += &format!("\t{} -- {} [{attributes}];\n", self.nodes[a], self.nodes[b]),
+                None => dot += &format!("\t{} -- {};\n", self.nodes[a], self.nodes[b])
+            }
+        }
+
+        dot + "}"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::*;
+
+    // two triangles joined by a single edge, and a lone pair
+    fn example() -> Graph<&'static str> {
+        let mut graph = Graph::new();
+
+        for (a, b) in [("a", "b"), ("b", "c"), ("c", "a"), ("c", "d"), ("d", "e"), ("e", "f"), ("f", "d"), ("x", "y")] {
+            graph.add_edge(a, b, ());
+        }
+
+        graph
+    }
+
+    #[test]
+    fn test_interning() {
+        let mut graph = example();
+
+        assert_eq!(graph.node_count(), 8);
+        assert_eq!(graph.edge_count(), 8);
+        assert_eq!(graph.id(&"c"), Some(2));
+        assert_eq!(graph.add_node("c"), 2);
+        assert_eq!(*graph.node(3), "d");
+        assert_eq!(graph.degree(2), 3);
+        assert_eq!(graph.find_edge(2, 3), Some(3));
+        assert_eq!(graph.find_edge(0, 3), None);
+    }
+
+    #[test]
+    fn test_traversal() {
+        let graph = example();
+
+        assert_eq!(graph.bfs(0), vec![(0, 0), (1, 1), (2, 1), (3, 2), (4, 3), (5, 3)]);
+        assert_eq!(graph.dfs(0), vec![0, 1, 2, 3, 4, 5]);
+        assert_eq!(graph.components(), vec![vec![0, 1, 2, 3, 4, 5], vec![6, 7]]);
+    }
+
+    #[test]
+    fn test_remove_edge() {
+        let mut graph = example();
+
+        assert_eq!(graph.remove_edge(3), Some(()));
+        assert_eq!(graph.remove_edge(3), None);
+        assert_eq!(graph.edge_count(), 7);
+        assert_eq!(graph.edge(4), Some((3, 4, &())));
+        assert_eq!(graph.components().len(), 3);
+    }
+
+    #[test]
+    fn test_cuts() {
+        let mut graph = example();
+        graph.remove_edge(7);
+
+        assert_eq!(graph.max_flow(0, 1), 2);
+        assert_eq!(graph.max_flow(0, 5), 1);
+        assert_eq!(graph.max_flow(0, 6), 0);
+        assert_eq!(graph.min_cut(0, 5), Cut { edges: vec![3], side: vec![true, true, true, false, false, false, false, false] });
+        assert_eq!(graph.global_min_cut().map(|cut| cut.edges), Some(vec![]));
+
+        // only the edge to y is a bridge once x also joins the triangles
+        let mut connected = example();
+        connected.add_edge("x", "a", ());
+        connected.add_edge("x", "f", ());
+
+        assert_eq!(connected.global_min_cut().map(|cut| cut.edges), Some(vec![7]));
+    }
+
+    #[test]
+    fn test_dot() {
+        let mut graph = Graph::new();
+        graph.add_edge("a", "b", 3);
+        graph.add_edge("b", "c", 5);
+
+        assert_eq!(graph.to_dot(|_, &w| (w > 4).then(|| "color=red".to_owned())), "graph {\n\ta -- b;\n\tb -- c [color=red];\n}");
+    }
+}
@@ -0,0 +1,229 @@
+use std::fmt::Display;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+use crate::geometry::{Direction4, Direction8, Point};
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), width * height, "a {width}x{height} grid needs {} cells", width * height);
+
+        Grid { width, height, cells }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self where T: Clone {
+        Grid::new(width, height, vec![value; width * height])
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point<usize>) -> bool {
+        point.x < self.width && point.y < self.height
+    }
+
+    pub fn get(&self, point: Point<usize>) -> Option<&T> {
+        self.contains(point).then(|| &self.cells[point.y * self.width + point.x])
+    }
+
+    pub fn get_mut(&mut self, point: Point<usize>) -> Option<&mut T> {
+        self.contains(point).then(|| &mut self.cells[point.y * self.width + point.x])
+    }
+
+    // moves from `point` by `delta`, unless that leaves the grid
+    pub fn offset<D: Into<Point<isize>>>(&self, point: Point<usize>, delta: D) -> Option<Point<usize>> {
+        point.checked_offset(delta).filter(|&point| self.contains(point))
+    }
+
+    pub fn neighbours4(&self, point: Point<usize>) -> impl Iterator<Item = Point<usize>> + '_ {
+        Direction4::ALL.into_iter().filter_map(move |direction| self.offset(point, direction))
+    }
+
+    pub fn neighbours8(&self, point: Point<usize>) -> impl Iterator<Item = Point<usize>> + '_ {
+        Direction8::ALL.into_iter().filter_map(move |direction| self.offset(point, direction))
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point<usize>> {
+        let width = self.width;
+
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point<usize>, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    pub fn position<P: Fn(&T) -> bool>(&self, predicate: P) -> Option<Point<usize>> {
+        self.iter().find(|(_, cell)| predicate(cell)).map(|(point, _)| point)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(x < self.width, "column {x} is out of bounds for a grid of width {}", self.width);
+
+        self.cells.iter().skip(x).step_by(self.width).take(self.height)
+    }
+
+    pub fn columns(&self) -> impl DoubleEndedIterator<Item = impl DoubleEndedIterator<Item = &T>> + ExactSizeIterator {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn transposed(&self) -> Self {
+        Grid::new(self.height, self.width, self.columns().flatten().cloned().collect())
+    }
+
+    pub fn rotated_clockwise(&self) -> Self {
+        Grid::new(self.height, self.width, self.columns().flat_map(|column| column.rev()).cloned().collect())
+    }
+
+    pub fn rotated_counterclockwise(&self) -> Self {
+        Grid::new(self.height, self.width, self.columns().rev().flatten().cloned().collect())
+    }
+}
+
+impl<T> Index<Point<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point<usize>) -> &Self::Output {
+        self.get(point).unwrap_or_else(|| panic!("{point:?} is out of bounds for a {}x{} grid", self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Point<usize>> for Grid<T> {
+    fn index_mut(&mut self, point: Point<usize>) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+
+        self.get_mut(point).unwrap_or_else(|| panic!("{point:?} is out of bounds for a {width}x{height} grid"))
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseGridError {
+    pub row: usize,
+    pub expected: usize,
+    pub found: usize
+}
+
+impl Display for ParseGridError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "row {} has {} cells, expected {}", self.row, self.found, self.expected)
+    }
+}
+
+impl std::error::Error for ParseGridError {}
+
+impl FromStr for Grid<char> {
+    type Err = ParseGridError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut cells = Vec::with_capacity(s.len());
+        let mut width = None;
+        let mut height = 0;
+
+        for (row, line) in s.lines().enumerate() {
+            let len = line.chars().count();
+            let expected = *width.get_or_insert(len);
+
+            if len != expected {
+                return Err(ParseGridError { row, expected, found: len });
+            }
+
+            cells.extend(line.chars());
+            height += 1;
+        }
+
+        Ok(Grid::new(width.unwrap_or(0), height, cells))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::geometry::Direction4;
+    use crate::grid::*;
+
+    #[test]
+    fn test_parse_and_display() {
+        let grid: Grid<char> = EXAMPLE.parse().unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(2, 1)], 'f');
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.to_string(), EXAMPLE);
+        assert_eq!("ab\nc".parse::<Grid<char>>(), Err(ParseGridError { row: 1, expected: 2, found: 1 }));
+        assert_eq!("".parse::<Grid<char>>().unwrap().points().count(), 0);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid: Grid<char> = EXAMPLE.parse().unwrap();
+
+        assert_eq!(grid.neighbours4(Point::new(0, 0)).collect::<Vec<_>>(), vec![Point::new(1, 0), Point::new(0, 1)]);
+        assert_eq!(grid.neighbours8(Point::new(1, 0)).count(), 5);
+        assert_eq!(grid.offset(Point::new(2, 1), (-2, -1)), Some(Point::new(0, 0)));
+        assert_eq!(grid.offset(Point::new(2, 1), Direction4::RIGHT), None);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid: Grid<char> = EXAMPLE.parse().unwrap();
+
+        assert_eq!(grid.rows().rev().map(|r| r.iter().collect::<String>()).collect::<Vec<_>>(), vec!["def", "abc"]);
+        assert_eq!(grid.columns().map(|c| c.collect::<String>()).collect::<Vec<_>>(), vec!["ad", "be", "cf"]);
+        assert_eq!(grid.position(|&c| c == 'e'), Some(Point::new(1, 1)));
+    }
+
+    #[test]
+    fn test_transform() {
+        let grid: Grid<char> = EXAMPLE.parse().unwrap();
+
+        assert_eq!(grid.transposed().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotated_clockwise().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotated_counterclockwise().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.rotated_clockwise().rotated_counterclockwise(), grid);
+        assert_eq!(grid.map(|c| c.is_ascii_lowercase()), Grid::filled(3, 2, true));
+    }
+
+    const EXAMPLE: &str = "abc
+def";
+}
@@ -0,0 +1,75 @@
+use std::iter::Peekable;
+
+pub trait IteratorExt: Iterator + Sized {
+    fn interspersed(self, separator: Self::Item) -> Interspersed<Self> where Self::Item: Clone {
+        Interspersed { iter: self.peekable(), separator, needs_separator: false }
+    }
+
+    fn map_windowed<const N: usize, R, F: FnMut(&[Self::Item; N]) -> R>(self, f: F) -> MapWindowed<Self, F, N> {
+        assert!(N > 0, "window size must be non-zero");
+
+        MapWindowed { iter: self, f, window: Vec::with_capacity(N) }
+    }
+}
+
+impl<I: Iterator> IteratorExt for I {}
+
+pub struct Interspersed<I: Iterator> {
+    iter: Peekable<I>,
+    separator: I::Item,
+    needs_separator: bool
+}
+
+impl<I: Iterator> Iterator for Interspersed<I> where I::Item: Clone {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.needs_separator && self.iter.peek().is_some() {
+            self.needs_separator = false;
+            Some(self.separator.clone())
+        } else {
+            self.needs_separator = true;
+            self.iter.next()
+        }
+    }
+}
+
+pub struct MapWindowed<I: Iterator, F, const N: usize> {
+    iter: I,
+    f: F,
+    window: Vec<I::Item>
+}
+
+impl<I: Iterator, R, F: FnMut(&[I::Item; N]) -> R, const N: usize> Iterator for MapWindowed<I, F, N> {
+    type Item = R;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.window.len() < N {
+            self.window.push(self.iter.next()?);
+        }
+
+        let result = (self.f)(self.window.as_slice().try_into().unwrap());
+        self.window.remove(0);
+
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::iter::*;
+
+    #[test]
+    fn test_interspersed() {
+        assert_eq!(["a", "b", "c"].into_iter().interspersed(",").collect::<String>(), "a,b,c");
+        assert_eq!(["a"].into_iter().interspersed(",").collect::<String>(), "a");
+        assert_eq!(std::iter::empty::<&str>().interspersed(",").count(), 0);
+    }
+
+    #[test]
+    fn test_map_windowed() {
+        assert_eq!([1, 2, 3, 4].iter().map_windowed(|[a, b]| *a + *b).collect::<Vec<_>>(), vec![3, 5, 7]);
+        assert_eq!((1..=5).map_windowed(|w: &[i32; 3]| w.iter().product::<i32>()).collect::<Vec<_>>(), vec![6, 24, 60]);
+        assert_eq!([1].iter().map_windowed(|[a, b]| *a + *b).count(), 0);
+    }
+}
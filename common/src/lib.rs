@@ -0,0 +1,229 @@
+use std::fmt::Display;
+use std::time::{Duration, Instant};
+
+pub mod geometry;
+pub mod graph;
+pub mod grid;
+pub mod iter;
+pub mod math;
+pub mod rational;
+pub mod search;
+
+pub trait Solution: Sized {
+    fn parse(input: &str) -> Self;
+
+    fn part1(&self) -> Answer;
+
+    fn part2(&self) -> Answer {
+        Answer::Unsolved
+    }
+
+    // a picture of how `part` was solved, for the days where that's worth seeing
+    fn visualize(&self, _part: Part, _style: Style) -> Option<String> {
+        None
+    }
+}
+
+pub fn solve<S: Solution>(input: String, part: Option<Part>) -> Run {
+    let (solution, parse) = timed(|| S::parse(&input));
+
+    run(&solution, parse, part)
+}
+
+pub type Pictures = Vec<(Part, Option<String>)>;
+
+// solves like `solve`, then draws every part that was solved from the same parsed input
+pub fn visualize<S: Solution>(input: String, part: Option<Part>, style: Style) -> (Run, Pictures) {
+    let (solution, parse) = timed(|| S::parse(&input));
+    let run = run(&solution, parse, part);
+    let pictures = [Part::One, Part::Two].into_iter()
+        .filter(|&p| part.is_none_or(|part| part == p))
+        .map(|p| (p, solution.visualize(p, style)))
+        .collect();
+
+    (run, pictures)
+}
+
+fn run<S: Solution>(solution: &S, parse: Duration, part: Option<Part>) -> Run {
+    let (part1, part1_time) = (part != Some(Part::Two)).then(|| timed(|| solution.part1())).unzip();
+    let (part2, part2_time) = (part != Some(Part::One)).then(|| timed(|| solution.part2())).unzip();
+
+    Run {
+        answers: Answers { part1, part2 },
+        timings: Timings { parse, part1: part1_time, part2: part2_time }
+    }
+}
+
+pub struct Puzzle {
+    pub year: i32,
+    pub day: usize,
+    pub title: &'static str,
+    pub solve: fn(String, Option<Part>) -> Run,
+    pub visualize: fn(String, Option<Part>, Style) -> (Run, Pictures)
+}
+
+#[macro_export]
+macro_rules! register {
+    ($solution:ty, year: $year:expr, day: $day:expr, title: $title:expr) => {
+        pub const PUZZLE: $crate::Puzzle = $crate::Puzzle {
+            year: $year,
+            day: $day,
+            title: $title,
+            solve: $crate::solve::<$solution>,
+            visualize: $crate::visualize::<$solution>
+        };
+    };
+}
+
+fn timed<T, F: FnOnce() -> T>(f: F) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+
+    (result, start.elapsed())
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Part {
+    One,
+    Two
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Style {
+    #[default]
+    Plain,
+    Ansi
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Answers {
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>
+}
+
+impl Answers {
+    pub fn get(&self, part: Part) -> Option<&Answer> {
+        match part {
+            Part::One => self.part1.as_ref(),
+            Part::Two => self.part2.as_ref()
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Option<Duration>,
+    pub part2: Option<Duration>
+}
+
+impl Timings {
+    pub fn get(&self, part: Part) -> Option<Duration> {
+        match part {
+            Part::One => self.part1,
+            Part::Two => self.part2
+        }
+    }
+
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1.unwrap_or_default() + self.part2.unwrap_or_default()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Run {
+    pub answers: Answers,
+    pub timings: Timings
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Answer {
+    Integer(i128),
+    Text(String),
+    Unsolved
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Integer(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+            Answer::Unsolved => write!(f, "unsolved")
+        }
+    }
+}
+
+macro_rules! answer_from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Integer(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+answer_from_integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, i128, isize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    struct Sum(Vec<usize>);
+
+    impl Solution for Sum {
+        fn parse(input: &str) -> Self {
+            Sum(input.split(' ').map(|x| x.parse().unwrap()).collect())
+        }
+
+        fn part1(&self) -> Answer {
+            self.0.iter().sum::<usize>().into()
+        }
+    }
+
+    #[test]
+    fn test_solve() {
+        let run = solve::<Sum>("1 2 3".to_owned(), None);
+
+        assert_eq!(run.answers, Answers { part1: Some(Answer::Integer(6)), part2: Some(Answer::Unsolved) });
+        assert!(run.timings.part1.is_some());
+        assert!(run.timings.part2.is_some());
+    }
+
+    #[test]
+    fn test_solve_single_part() {
+        let run = solve::<Sum>("1 2 3".to_owned(), Some(Part::Two));
+
+        assert_eq!(run.answers.get(Part::One), None);
+        assert_eq!(run.answers.get(Part::Two), Some(&Answer::Unsolved));
+        assert_eq!(run.timings.get(Part::One), None);
+    }
+
+    #[test]
+    fn test_visualize() {
+        let (run, pictures) = visualize::<Sum>("1 2 3".to_owned(), Some(Part::One), Style::Plain);
+
+        assert_eq!(run.answers.get(Part::One), Some(&Answer::Integer(6)));
+        assert_eq!(pictures, vec![(Part::One, None)]);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::from(-5isize).to_string(), "-5");
+        assert_eq!(Answer::from("abc").to_string(), "abc");
+    }
+}
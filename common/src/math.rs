@@ -0,0 +1,217 @@
+use std::fmt::Debug;
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+pub trait Integer: Copy + Ord + Debug
+    + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self> + Rem<Output = Self> {
+
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn checked_rem(self, rhs: Self) -> Option<Self>;
+    fn checked_neg(self) -> Option<Self>;
+    fn rem_euclid(self, rhs: Self) -> Self;
+    fn isqrt(self) -> Self;
+}
+
+pub trait SignedInteger: Integer + Neg<Output = Self> {}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_add(self, rhs)
+                }
+
+                fn checked_sub(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, rhs)
+                }
+
+                fn checked_mul(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, rhs)
+                }
+
+                fn checked_rem(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_rem(self, rhs)
+                }
+
+                fn checked_neg(self) -> Option<Self> {
+                    <$t>::checked_neg(self)
+                }
+
+                fn rem_euclid(self, rhs: Self) -> Self {
+                    <$t>::rem_euclid(self, rhs)
+                }
+
+                fn isqrt(self) -> Self {
+                    <$t>::isqrt(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+macro_rules! impl_signed_integer {
+    ($($t:ty),*) => {
+        $(impl SignedInteger for $t {})*
+    };
+}
+
+impl_signed_integer!(i8, i16, i32, i64, i128, isize);
+
+fn checked_abs<T: Integer>(n: T) -> Option<T> {
+    if n < T::ZERO { n.checked_neg() } else { Some(n) }
+}
+
+// the result is never negative, so it only overflows when it would be `T::MAX + 1`, e.g. gcd(i32::MIN, 0)
+pub fn checked_gcd<T: Integer>(a: T, b: T) -> Option<T> {
+    let (mut a, mut b) = (a, b);
+
+    while b != T::ZERO {
+        // only `MIN % -1` fails, and its remainder is 0
+        (a, b) = (b, a.checked_rem(b).unwrap_or(T::ZERO));
+    }
+
+    checked_abs(a)
+}
+
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    checked_gcd(a, b).unwrap_or_else(|| panic!("gcd({a:?}, {b:?}) overflows"))
+}
+
+pub fn checked_lcm<T: Integer>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+
+    checked_abs((a / checked_gcd(a, b)?).checked_mul(b)?)
+}
+
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+    checked_lcm(a, b).unwrap_or_else(|| panic!("lcm({a:?}, {b:?}) overflows"))
+}
+
+// returns (g, x, y) such that a * x + b * y = g = gcd(a, b)
+pub fn extended_gcd<T: SignedInteger>(a: T, b: T) -> (T, T, T) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::ONE, T::ZERO);
+    let (mut old_y, mut y) = (T::ZERO, T::ONE);
+
+    while r != T::ZERO {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+
+    if old_r < T::ZERO {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+// None if the modulus isn't positive or `a` has no inverse
+pub fn mod_inverse<T: SignedInteger>(a: T, modulus: T) -> Option<T> {
+    if modulus <= T::ZERO {
+        return None;
+    }
+
+    let (g, x, _) = extended_gcd(a.rem_euclid(modulus), modulus);
+
+    (g == T::ONE).then(|| x.rem_euclid(modulus))
+}
+
+// solves x = r (mod m) for every (r, m), returning (x, lcm of the moduli) with 0 <= x < lcm;
+// moduli don't have to be coprime. None if a modulus isn't positive, there's no solution or it doesn't fit in T
+pub fn crt<T: SignedInteger>(congruences: &[(T, T)]) -> Option<(T, T)> {
+    congruences.iter().try_fold((T::ZERO, T::ONE), |(r1, m1), &(r2, m2)| {
+        if m2 <= T::ZERO {
+            return None;
+        }
+
+        let (g, p, _) = extended_gcd(m1, m2);
+        let diff = r2.rem_euclid(m2).checked_sub(r1)?;
+
+        if diff % g != T::ZERO {
+            return None;
+        }
+
+        let m2g = m2 / g;
+        let k = (diff / g % m2g).checked_mul(p % m2g)?.rem_euclid(m2g);
+        let m = m1.checked_mul(m2g)?;
+
+        Some((r1.checked_add(m1.checked_mul(k)?)?.rem_euclid(m), m))
+    })
+}
+
+// the largest x with x * x <= n, None if n is negative
+pub fn isqrt<T: Integer>(n: T) -> Option<T> {
+    (n >= T::ZERO).then(|| n.isqrt())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::*;
+
+    #[test]
+    fn test_gcd() {
+        assert_eq!(gcd(12u32, 18), 6);
+        assert_eq!(gcd(0usize, 7), 7);
+        assert_eq!(gcd(-12i64, 18), 6);
+        assert_eq!(gcd(i32::MIN, -1), 1);
+        assert_eq!(checked_gcd(i32::MIN, 0), None);
+    }
+
+    #[test]
+    fn test_lcm() {
+        assert_eq!(lcm(4u8, 6), 12);
+        assert_eq!(lcm(-4i32, 6), 12);
+        assert_eq!(lcm(0u64, 5), 0);
+        assert_eq!([11911usize, 21883, 16343, 16579, 18559, 13019].into_iter().reduce(lcm), Some(49125423585599));
+        assert_eq!(checked_lcm(u8::MAX, 2), None);
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        assert_eq!(extended_gcd(240i64, 46), (2, -9, 47));
+        assert_eq!(extended_gcd(-3i32, 0), (3, -1, 0));
+    }
+
+    #[test]
+    fn test_mod_inverse() {
+        assert_eq!(mod_inverse(3i32, 11), Some(4));
+        assert_eq!(mod_inverse(-3i32, 11), Some(7));
+        assert_eq!(mod_inverse(6i32, 9), None);
+        assert_eq!(mod_inverse(3i32, 0), None);
+        assert_eq!(mod_inverse(3i32, -7), None);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt(&[(2i64, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt(&[(3i64, 4), (1, 6)]), Some((7, 12)));
+        assert_eq!(crt(&[(0i64, 4), (1, 6)]), None);
+        assert_eq!(crt::<i32>(&[]), Some((0, 1)));
+        assert_eq!(crt(&[(1i8, 100), (2, 99)]), None);
+        assert_eq!(crt(&[(1i64, 0)]), None);
+        assert_eq!(crt(&[(2i64, 3), (1, -4)]), None);
+    }
+
+    #[test]
+    fn test_isqrt() {
+        assert_eq!(isqrt(0u32), Some(0));
+        assert_eq!(isqrt(24u64), Some(4));
+        assert_eq!(isqrt(25i32), Some(5));
+        assert_eq!(isqrt(u64::MAX), Some(u32::MAX as u64));
+        assert_eq!(isqrt(-1i32), None);
+    }
+}
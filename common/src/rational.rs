@@ -0,0 +1,501 @@
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display};
+use std::iter::{Product, Sum};
+use std::ops::{Add, Div, Mul, Neg, Sub};
+use std::str::FromStr;
+
+pub trait RationalInteger: Clone + Ord + Debug + Display + FromStr {
+    fn zero() -> Self;
+    fn one() -> Self;
+    fn from_i64(value: i64) -> Option<Self>;
+    fn to_f64(&self) -> f64;
+
+    fn checked_add(&self, rhs: &Self) -> Option<Self>;
+    fn checked_sub(&self, rhs: &Self) -> Option<Self>;
+    fn checked_mul(&self, rhs: &Self) -> Option<Self>;
+    fn checked_div(&self, rhs: &Self) -> Option<Self>;
+    fn checked_neg(&self) -> Option<Self>;
+    fn checked_gcd(&self, rhs: &Self) -> Option<Self>;
+    fn div_rem_euclid(&self, rhs: &Self) -> (Self, Self);
+}
+
+macro_rules! impl_rational_integer {
+    ($($t:ty),*) => {
+        $(
+            impl RationalInteger for $t {
+                fn zero() -> Self {
+                    0
+                }
+
+                fn one() -> Self {
+                    1
+                }
+
+                fn from_i64(value: i64) -> Option<Self> {
+                    value.try_into().ok()
+                }
+
+                fn to_f64(&self) -> f64 {
+                    *self as f64
+                }
+
+                fn checked_add(&self, rhs: &Self) -> Option<Self> {
+                    <$t>::checked_add(*self, *rhs)
+                }
+
+                fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+                    <$t>::checked_sub(*self, *rhs)
+                }
+
+                fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+                    <$t>::checked_mul(*self, *rhs)
+                }
+
+                fn checked_div(&self, rhs: &Self) -> Option<Self> {
+                    <$t>::checked_div(*self, *rhs)
+                }
+
+                fn checked_neg(&self) -> Option<Self> {
+                    <$t>::checked_neg(*self)
+                }
+
+                fn checked_gcd(&self, rhs: &Self) -> Option<Self> {
+                    crate::math::checked_gcd(*self, *rhs)
+                }
+
+                fn div_rem_euclid(&self, rhs: &Self) -> (Self, Self) {
+                    (self.div_euclid(*rhs), self.rem_euclid(*rhs))
+                }
+            }
+        )*
+    };
+}
+
+impl_rational_integer!(i8, i16, i32, i64, i128, isize);
+
+#[cfg(feature = "bigint")]
+impl RationalInteger for num_bigint::BigInt {
+    fn zero() -> Self {
+        0.into()
+    }
+
+    fn one() -> Self {
+        1.into()
+    }
+
+    fn from_i64(value: i64) -> Option<Self> {
+        Some(value.into())
+    }
+
+    fn to_f64(&self) -> f64 {
+        num_traits::ToPrimitive::to_f64(self).unwrap_or(f64::NAN)
+    }
+
+    fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        Some(self + rhs)
+    }
+
+    fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        Some(self - rhs)
+    }
+
+    fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        Some(self * rhs)
+    }
+
+    fn checked_div(&self, rhs: &Self) -> Option<Self> {
+        (rhs != &Self::zero()).then(|| self / rhs)
+    }
+
+    fn checked_neg(&self) -> Option<Self> {
+        Some(-self)
+    }
+
+    fn checked_gcd(&self, rhs: &Self) -> Option<Self> {
+        let (mut a, mut b) = (self.clone(), rhs.clone());
+
+        while b != Self::zero() {
+            (a, b) = (b.clone(), a % b);
+        }
+
+        Some(if a < Self::zero() { -a } else { a })
+    }
+
+    fn div_rem_euclid(&self, rhs: &Self) -> (Self, Self) {
+        let (q, r) = (self / rhs, self % rhs);
+
+        match r < Self::zero() {
+            true if rhs > &Self::zero() => (q - 1, r + rhs),
+            true => (q + 1, r - rhs),
+            false => (q, r)
+        }
+    }
+}
+
+// always kept in lowest terms with a positive denominator, so the derived equality is exact
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Rational<T> {
+    numer: T,
+    denom: T
+}
+
+impl<T: RationalInteger> Rational<T> {
+    pub fn new(numer: T, denom: T) -> Self {
+        assert!(denom != T::zero(), "denominator of {numer}/{denom} is zero");
+
+        Rational::checked_new(numer, denom).expect("rational overflow")
+    }
+
+    pub fn checked_new(numer: T, denom: T) -> Option<Self> {
+        if denom == T::zero() {
+            return None;
+        }
+
+        let g = numer.checked_gcd(&denom)?;
+        let (numer, denom) = (numer.checked_div(&g)?, denom.checked_div(&g)?);
+
+        if denom < T::zero() {
+            Some(Rational { numer: numer.checked_neg()?, denom: denom.checked_neg()? })
+        } else {
+            Some(Rational { numer, denom })
+        }
+    }
+
+    pub fn zero() -> Self {
+        Rational { numer: T::zero(), denom: T::one() }
+    }
+
+    pub fn one() -> Self {
+        Rational { numer: T::one(), denom: T::one() }
+    }
+
+    pub fn numer(&self) -> &T {
+        &self.numer
+    }
+
+    pub fn denom(&self) -> &T {
+        &self.denom
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.denom == T::one()
+    }
+
+    pub fn to_integer(&self) -> Option<T> {
+        self.is_integer().then(|| self.numer.clone())
+    }
+
+    pub fn checked_recip(&self) -> Option<Self> {
+        Rational::checked_new(self.denom.clone(), self.numer.clone())
+    }
+
+    pub fn recip(&self) -> Self {
+        self.checked_recip().expect("reciprocal of zero")
+    }
+
+    pub fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        let g = self.denom.checked_gcd(&rhs.denom)?;
+        let (a, b) = (self.denom.checked_div(&g)?, rhs.denom.checked_div(&g)?);
+        let numer = self.numer.checked_mul(&b)?.checked_add(&rhs.numer.checked_mul(&a)?)?;
+
+        Rational::checked_new(numer, a.checked_mul(&rhs.denom)?)
+    }
+
+    pub fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        self.checked_add(&rhs.checked_neg()?)
+    }
+
+    pub fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        // cancelling before multiplying keeps the intermediate values as small as possible
+        let g1 = self.numer.checked_gcd(&rhs.denom)?.max(T::one());
+        let g2 = rhs.numer.checked_gcd(&self.denom)?.max(T::one());
+        let numer = self.numer.checked_div(&g1)?.checked_mul(&rhs.numer.checked_div(&g2)?)?;
+        let denom = self.denom.checked_div(&g2)?.checked_mul(&rhs.denom.checked_div(&g1)?)?;
+
+        Rational::checked_new(numer, denom)
+    }
+
+    pub fn checked_div(&self, rhs: &Self) -> Option<Self> {
+        self.checked_mul(&rhs.checked_recip()?)
+    }
+
+    pub fn checked_neg(&self) -> Option<Self> {
+        Some(Rational { numer: self.numer.checked_neg()?, denom: self.denom.clone() })
+    }
+
+    pub fn to_f64(&self) -> f64 {
+        self.numer.to_f64() / self.denom.to_f64()
+    }
+
+    // exact, so None if the value doesn't fit in T or isn't finite
+    pub fn from_f64(value: f64) -> Option<Self> {
+        if !value.is_finite() {
+            return None;
+        } else if value == 0.0 {
+            return Some(Rational::zero());
+        }
+
+        let bits = value.to_bits();
+        let biased_exponent = ((bits >> 52) & 0x7ff) as i32;
+        let mut mantissa = match biased_exponent {
+            0 => (bits & 0xf_ffff_ffff_ffff) << 1,
+            _ => (bits & 0xf_ffff_ffff_ffff) | 0x10_0000_0000_0000
+        } as i64;
+        let mut exponent = biased_exponent - 1075;
+
+        while mantissa % 2 == 0 && exponent < 0 {
+            mantissa /= 2;
+            exponent += 1;
+        }
+
+        let sign = if bits >> 63 == 1 { -1 } else { 1 };
+        let two = T::from_i64(2)?;
+        let power = (0..exponent.unsigned_abs()).try_fold(T::one(), |acc, _| acc.checked_mul(&two))?;
+        let mantissa = T::from_i64(sign * mantissa)?;
+
+        if exponent >= 0 {
+            Rational::checked_new(mantissa.checked_mul(&power)?, T::one())
+        } else {
+            Rational::checked_new(mantissa, power)
+        }
+    }
+}
+
+impl<T: RationalInteger> From<T> for Rational<T> {
+    fn from(value: T) -> Self {
+        Rational { numer: value, denom: T::one() }
+    }
+}
+
+impl<T: RationalInteger> PartialOrd for Rational<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: RationalInteger> Ord for Rational<T> {
+    // compares the continued fraction expansions, which never needs a product that could overflow
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (mut a, mut b) = (self.numer.clone(), self.denom.clone());
+        let (mut c, mut d) = (other.numer.clone(), other.denom.clone());
+        let mut reversed = false;
+
+        loop {
+            let ((qa, ra), (qc, rc)) = (a.div_rem_euclid(&b), c.div_rem_euclid(&d));
+            let ordering = match qa.cmp(&qc) {
+                Ordering::Equal => match (ra == T::zero(), rc == T::zero()) {
+                    (true, true) => Ordering::Equal,
+                    (true, false) => Ordering::Less,
+                    (false, true) => Ordering::Greater,
+                    (false, false) => {
+                        // ra/b < rc/d exactly when b/ra > d/rc
+                        (a, b, c, d) = (b, ra, d, rc);
+                        reversed = !reversed;
+                        continue;
+                    }
+                },
+                ordering => ordering
+            };
+
+            return if reversed { ordering.reverse() } else { ordering };
+        }
+    }
+}
+
+impl<T: RationalInteger> Display for Rational<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numer)
+        } else {
+            write!(f, "{}/{}", self.numer, self.denom)
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseRationalError {
+    Invalid(String),
+    ZeroDenominator,
+    Overflow
+}
+
+impl Display for ParseRationalError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseRationalError::Invalid(s) => write!(f, "`{s}` is not a valid rational number"),
+            ParseRationalError::ZeroDenominator => write!(f, "denominator is zero"),
+            ParseRationalError::Overflow => write!(f, "rational number is too large")
+        }
+    }
+}
+
+impl std::error::Error for ParseRationalError {}
+
+// accepts `p/q`, integers and decimals like `-1.25`
+impl<T: RationalInteger> FromStr for Rational<T> {
+    type Err = ParseRationalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ParseRationalError::Invalid(s.to_owned());
+        let parse = |s: &str| s.trim().parse::<T>().map_err(|_| invalid());
+
+        let (numer, denom) = if let Some((numer, denom)) = s.split_once('/') {
+            (parse(numer)?, parse(denom)?)
+        } else if let Some((whole, fraction)) = s.split_once('.') {
+            if fraction.is_empty() || !fraction.chars().all(|c| c.is_ascii_digit()) {
+                return Err(invalid());
+            }
+
+            (parse(&format!("{}{fraction}", whole.trim()))?, parse(&format!("1{}", "0".repeat(fraction.len())))?)
+        } else {
+            (parse(s)?, T::one())
+        };
+
+        if denom == T::zero() {
+            return Err(ParseRationalError::ZeroDenominator);
+        }
+
+        Rational::checked_new(numer, denom).ok_or(ParseRationalError::Overflow)
+    }
+}
+
+impl<T: RationalInteger> Add for Rational<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(&rhs).expect("rational overflow")
+    }
+}
+
+impl<T: RationalInteger> Sub for Rational<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_sub(&rhs).expect("rational overflow")
+    }
+}
+
+impl<T: RationalInteger> Mul for Rational<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.checked_mul(&rhs).expect("rational overflow")
+    }
+}
+
+impl<T: RationalInteger> Div for Rational<T> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self.checked_div(&rhs).expect("rational overflow or division by zero")
+    }
+}
+
+impl<T: RationalInteger> Neg for Rational<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.checked_neg().expect("rational overflow")
+    }
+}
+
+impl<T: RationalInteger> Sum for Rational<T> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Rational::zero(), |acc, r| acc + r)
+    }
+}
+
+impl<T: RationalInteger> Product for Rational<T> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Rational::one(), |acc, r| acc * r)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rational::*;
+
+    fn r(numer: i64, denom: i64) -> Rational<i64> {
+        Rational::new(numer, denom)
+    }
+
+    #[test]
+    fn test_new() {
+        assert_eq!(r(4, -6), r(-2, 3));
+        assert_eq!((r(-2, 3).numer(), r(-2, 3).denom()), (&-2, &3));
+        assert_eq!(r(0, -5), Rational::zero());
+        assert_eq!(Rational::checked_new(1, 0), None);
+        assert_eq!(Rational::checked_new(i64::MIN, -1), None);
+        assert_eq!(r(6, 3).to_integer(), Some(2));
+        assert_eq!(r(1, 3).to_integer(), None);
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        assert_eq!(r(2, 3) + r(3, 4), r(17, 12));
+        assert_eq!(r(2, 3) - r(3, 4), r(-1, 12));
+        assert_eq!(r(1, 6) * r(3, 14), r(1, 28));
+        assert_eq!(r(1, 6) / r(3, 2), r(1, 9));
+        assert_eq!([r(1, 3), r(3, 4), r(5, 2)].into_iter().sum::<Rational<i64>>(), r(43, 12));
+        assert_eq!([r(1, 3), r(3, 4), r(-2, 1)].into_iter().product::<Rational<i64>>(), r(-1, 2));
+    }
+
+    #[test]
+    fn test_checked() {
+        let big = Rational::from(i64::MAX);
+
+        assert_eq!(big.checked_add(&Rational::one()), None);
+        assert_eq!(big.checked_mul(&r(2, 1)), None);
+        assert_eq!(big.checked_mul(&r(1, 7)), Some(r(i64::MAX / 7, 1)));
+        assert_eq!(Rational::<i64>::zero().checked_recip(), None);
+        assert_eq!(r(1, 2).checked_div(&Rational::zero()), None);
+    }
+
+    #[test]
+    fn test_ord() {
+        assert!(r(1, 3) < r(1, 2));
+        assert!(r(-1, 2) < r(-1, 3));
+        assert!(r(7, 5) > r(4, 3));
+        assert_eq!(r(2, 4).cmp(&r(1, 2)), Ordering::Equal);
+        assert!(Rational::new(i64::MAX - 1, i64::MAX) < Rational::new(i64::MAX - 2, i64::MAX - 1).recip());
+        assert!(Rational::new(i64::MAX - 2, i64::MAX - 1) < Rational::new(i64::MAX - 1, i64::MAX));
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("3/-6".parse::<Rational<i64>>(), Ok(r(-1, 2)));
+        assert_eq!(" 42 ".parse::<Rational<i64>>(), Ok(r(42, 1)));
+        assert_eq!("-1.25".parse::<Rational<i64>>(), Ok(r(-5, 4)));
+        assert_eq!("1/0".parse::<Rational<i64>>(), Err(ParseRationalError::ZeroDenominator));
+        assert_eq!("1.".parse::<Rational<i64>>(), Err(ParseRationalError::Invalid("1.".to_owned())));
+        assert!("a/2".parse::<Rational<i64>>().is_err());
+        assert_eq!(r(-5, 4).to_string(), "-5/4");
+        assert_eq!(r(8, 4).to_string(), "2");
+    }
+
+    #[test]
+    fn test_f64() {
+        assert_eq!(r(-5, 4).to_f64(), -1.25);
+        assert_eq!(Rational::<i64>::from_f64(0.375), Some(r(3, 8)));
+        assert_eq!(Rational::<i64>::from_f64(-3e10), Some(r(-30000000000, 1)));
+        assert_eq!(Rational::<i64>::from_f64(0.0), Some(Rational::zero()));
+        assert_eq!(Rational::<i64>::from_f64(1e300), None);
+        assert_eq!(Rational::<i64>::from_f64(f64::NAN), None);
+        assert_eq!(Rational::<i64>::from_f64(0.1).map(|x| x.to_f64()), Some(0.1));
+    }
+
+    #[cfg(feature = "bigint")]
+    #[test]
+    fn test_bigint() {
+        use num_bigint::BigInt;
+
+        let huge = Rational::<BigInt>::from_f64(1e300).unwrap();
+        let tiny = Rational::<BigInt>::from_f64(5e-324).unwrap();
+
+        assert_eq!(huge.to_f64(), 1e300);
+        assert!(tiny > Rational::zero() && tiny < Rational::from_f64(1e-323).unwrap());
+        assert_eq!((huge.clone() * tiny.clone()).recip() * huge * tiny, Rational::one());
+        assert_eq!("-10/4".parse::<Rational<BigInt>>().unwrap().to_string(), "-5/2");
+        assert!(Rational::<BigInt>::new((-7).into(), 2.into()) < Rational::new((-3).into(), 1.into()));
+    }
+}
@@ -0,0 +1,192 @@
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+
+use crate::math::Integer;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Path<S, C> {
+    pub cost: C,
+    // from the start to the goal, both included
+    pub states: Vec<S>
+}
+
+pub fn dijkstra<S, C, I, N, G>(starts: I, successors: N, is_goal: G) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Integer,
+    I: IntoIterator<Item = S>,
+    N: FnMut(&S) -> Vec<(S, C)>,
+    G: FnMut(&S) -> bool
+{
+    astar(starts, successors, is_goal, |_| C::ZERO)
+}
+
+// the heuristic must never overestimate the remaining cost, otherwise the path might not be the cheapest
+pub fn astar<S, C, I, N, G, H>(starts: I, successors: N, is_goal: G, heuristic: H) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Integer,
+    I: IntoIterator<Item = S>,
+    N: FnMut(&S) -> Vec<(S, C)>,
+    G: FnMut(&S) -> bool,
+    H: FnMut(&S) -> C
+{
+    let (explored, goal) = explore(starts, successors, is_goal, heuristic, None);
+
+    goal.map(|id| Path { cost: explored.costs[id], states: explored.path(id) })
+}
+
+// the cheapest cost of every state reachable for at most `max_cost`
+pub fn distances_within<S, C, I, N>(starts: I, successors: N, max_cost: C) -> HashMap<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Integer,
+    I: IntoIterator<Item = S>,
+    N: FnMut(&S) -> Vec<(S, C)>
+{
+    let (explored, _) = explore(starts, successors, |_| false, |_| C::ZERO, Some(max_cost));
+
+    explored.index.into_iter().map(|(state, id)| (state, explored.costs[id])).collect()
+}
+
+struct Explored<S, C> {
+    index: HashMap<S, usize>,
+    states: Vec<S>,
+    costs: Vec<C>,
+    parents: Vec<Option<usize>>
+}
+
+impl<S: Clone + Eq + Hash, C: Integer> Explored<S, C> {
+    // records `state` if it's new or `cost` is cheaper than what we had, returning its id in that case
+    fn relax(&mut self, state: S, cost: C, parent: Option<usize>) -> Option<usize> {
+        match self.index.entry(state) {
+            Entry::Occupied(entry) => {
+                let id = *entry.get();
+
+                (cost < self.costs[id]).then(|| {
+                    self.costs[id] = cost;
+                    self.parents[id] = parent;
+                    id
+                })
+            },
+            Entry::Vacant(entry) => {
+                let id = self.states.len();
+
+                self.states.push(entry.key().clone());
+                self.costs.push(cost);
+                self.parents.push(parent);
+                entry.insert(id);
+
+                Some(id)
+            }
+        }
+    }
+
+    fn path(&self, id: usize) -> Vec<S> {
+        let mut path = vec![self.states[id].clone()];
+        let mut current = id;
+
+        while let Some(parent) = self.parents[current] {
+            path.push(self.states[parent].clone());
+            current = parent;
+        }
+
+        path.reverse();
+        path
+    }
+}
+
+fn explore<S, C, I, N, G, H>(starts: I, mut successors: N, mut is_goal: G, mut heuristic: H, max_cost: Option<C>) -> (Explored<S, C>, Option<usize>)
+where
+    S: Clone + Eq + Hash,
+    C: Integer,
+    I: IntoIterator<Item = S>,
+    N: FnMut(&S) -> Vec<(S, C)>,
+    G: FnMut(&S) -> bool,
+    H: FnMut(&S) -> C
+{
+    let mut explored = Explored { index: HashMap::new(), states: Vec::new(), costs: Vec::new(), parents: Vec::new() };
+    let mut pq = BinaryHeap::new();
+
+    for start in starts {
+        if let Some(id) = explored.relax(start, C::ZERO, None) {
+            pq.push(Reverse((heuristic(&explored.states[id]), C::ZERO, id)));
+        }
+    }
+
+    while let Some(Reverse((_, cost, id))) = pq.pop() {
+        // a cheaper way to this state was found after this entry was queued
+        if cost > explored.costs[id] {
+            continue;
+        }
+
+        let state = explored.states[id].clone();
+
+        if is_goal(&state) {
+            return (explored, Some(id));
+        }
+
+        for (next, step) in successors(&state) {
+            let next_cost = cost + step;
+
+            if max_cost.is_some_and(|max_cost| next_cost > max_cost) {
+                continue;
+            }
+
+            if let Some(next_id) = explored.relax(next, next_cost, Some(id)) {
+                pq.push(Reverse((next_cost + heuristic(&explored.states[next_id]), next_cost, next_id)));
+            }
+        }
+    }
+
+    (explored, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::search::*;
+
+    fn graph(node: &char) -> Vec<(char, u32)> {
+        match node {
+            'a' => vec![('b', 7), ('c', 9), ('f', 14)],
+            'b' => vec![('c', 10), ('d', 15)],
+            'c' => vec![('d', 11), ('f', 2)],
+            'd' => vec![('e', 6)],
+            'f' => vec![('e', 9)],
+            _ => vec![]
+        }
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        assert_eq!(dijkstra(['a'], graph, |&n| n == 'e'), Some(Path { cost: 20, states: vec!['a', 'c', 'f', 'e'] }));
+        assert_eq!(dijkstra(['d', 'b'], graph, |&n| n == 'e'), Some(Path { cost: 6, states: vec!['d', 'e'] }));
+        assert_eq!(dijkstra(['a'], graph, |&n| n == 'a'), Some(Path { cost: 0, states: vec!['a'] }));
+        assert_eq!(dijkstra(['e'], graph, |&n| n == 'a'), None);
+    }
+
+    #[test]
+    fn test_astar() {
+        let walls = [(1, 0), (1, 1), (1, 2), (3, 4), (3, 3), (3, 2)];
+        let successors = |&(x, y): &(i32, i32)| [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+            .into_iter()
+            .filter(|&(x, y)| (0..5).contains(&x) && (0..5).contains(&y) && !walls.contains(&(x, y)))
+            .map(|next| (next, 1))
+            .collect();
+        let path = astar([(0, 0)], successors, |&p| p == (4, 4), |&(x, y)| (4 - x) + (4 - y)).unwrap();
+
+        assert_eq!(path.cost, 12);
+        assert_eq!(path.states.len(), 13);
+        assert!(path.states.windows(2).all(|w| (w[0].0 - w[1].0).abs() + (w[0].1 - w[1].1).abs() == 1));
+    }
+
+    #[test]
+    fn test_distances_within() {
+        let distances = distances_within(['a'], graph, 11);
+
+        assert_eq!(distances.len(), 4);
+        assert_eq!((distances[&'a'], distances[&'b'], distances[&'c'], distances[&'f']), (0, 7, 9, 11));
+    }
+}
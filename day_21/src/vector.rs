@@ -1,4 +0,0 @@
-use crate::fraction::Fraction;
-
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
-pub struct Vector(pub [Fraction; 3]);
\ No newline at end of file
@@ -0,0 +1,20 @@
+use std::{env, fs, path::Path};
+
+// every `year_YYYY_day_DD` dependency of the runner exports a `PUZZLE` registered with `common::register!`
+fn main() {
+    println!("cargo:rerun-if-changed=Cargo.toml");
+
+    let manifest: toml::Table = toml::from_str(&fs::read_to_string("Cargo.toml").expect("Couldn't read Cargo.toml"))
+        .expect("Invalid Cargo.toml");
+    let days: Vec<_> = manifest["dependencies"].as_table()
+        .expect("Invalid dependencies table")
+        .keys()
+        .filter(|name| name.starts_with("year_"))
+        .map(|name| format!("    {name}::PUZZLE,\n"))
+        .collect();
+
+    let registry = format!("pub const PUZZLES: &[Puzzle] = &[\n{}];\n", days.concat());
+    let path = Path::new(&env::var("OUT_DIR").unwrap()).join("puzzles.rs");
+
+    fs::write(path, registry).expect("Couldn't write the puzzle registry");
+}
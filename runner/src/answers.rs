@@ -0,0 +1,350 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+
+use common::{Answer, Answers, Part};
+use serde::{Deserialize, Serialize};
+
+use crate::error::RunnerError;
+
+pub fn answers_path(year: i32) -> String {
+    format!("input/{year}/answers.toml")
+}
+
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct AnswerFile {
+    days: BTreeMap<String, RecordedAnswers>
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+struct RecordedAnswers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part2: Option<String>,
+    // whether the server confirmed the answer, rather than it only being recorded from a run
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    accepted_part1: bool,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    accepted_part2: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    rejected_part1: Vec<Rejected>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    rejected_part2: Vec<Rejected>
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+struct Rejected {
+    answer: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hint: Option<Hint>
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Hint {
+    TooHigh,
+    TooLow
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Guard {
+    Accepted(String),
+    Rejected,
+    TooHigh(String),
+    TooLow(String)
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Status {
+    Pass,
+    Fail,
+    Missing
+}
+
+impl AnswerFile {
+    // a missing file has no answers yet
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, RunnerError> {
+        match fs::read_to_string(&path) {
+            Ok(s) => AnswerFile::parse(&s),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Default::default()),
+            Err(source) => Err(RunnerError::AnswersRead { path: path.as_ref().display().to_string(), source })
+        }
+    }
+
+    pub fn parse(s: &str) -> Result<Self, RunnerError> {
+        Ok(AnswerFile { days: toml::from_str(s).map_err(RunnerError::InvalidAnswers)? })
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), RunnerError> {
+        let path = path.as_ref();
+        let write_error = |source| RunnerError::AnswersWrite { path: path.display().to_string(), source };
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(write_error)?;
+        }
+
+        fs::write(path, self.to_string()).map_err(write_error)
+    }
+
+    pub fn get(&self, day: usize, part: Part) -> Option<&str> {
+        let recorded = self.days.get(&key(day))?;
+
+        match part {
+            Part::One => recorded.part1.as_deref(),
+            Part::Two => recorded.part2.as_deref()
+        }
+    }
+
+    pub fn accept(&mut self, day: usize, part: Part, answer: &str) {
+        let recorded = self.days.entry(key(day)).or_default();
+
+        let (slot, accepted) = match part {
+            Part::One => (&mut recorded.part1, &mut recorded.accepted_part1),
+            Part::Two => (&mut recorded.part2, &mut recorded.accepted_part2)
+        };
+
+        *slot = Some(answer.to_owned());
+        *accepted = true;
+    }
+
+    pub fn reject(&mut self, day: usize, part: Part, answer: &str, hint: Option<Hint>) {
+        let recorded = self.days.entry(key(day)).or_default();
+        let rejected = match part {
+            Part::One => &mut recorded.rejected_part1,
+            Part::Two => &mut recorded.rejected_part2
+        };
+
+        if rejected.iter().all(|r| r.answer != answer) {
+            rejected.push(Rejected { answer: answer.to_owned(), hint });
+        }
+    }
+
+    // only answers the server has seen block a submission, not ones recorded from a run
+    pub fn guard(&self, day: usize, part: Part, answer: &str) -> Option<Guard> {
+        let recorded = self.days.get(&key(day))?;
+        let (accepted, rejected) = match part {
+            Part::One => (recorded.accepted_part1.then_some(&recorded.part1), &recorded.rejected_part1),
+            Part::Two => (recorded.accepted_part2.then_some(&recorded.part2), &recorded.rejected_part2)
+        };
+
+        if let Some(Some(accepted)) = accepted {
+            return Some(Guard::Accepted(accepted.clone()));
+        }
+        let value = answer.parse::<i128>().ok();
+
+        for r in rejected {
+            if r.answer == answer {
+                return Some(Guard::Rejected);
+            }
+
+            match (value, r.answer.parse::<i128>().ok(), r.hint) {
+                (Some(value), Some(bound), Some(Hint::TooHigh)) if value > bound => return Some(Guard::TooHigh(r.answer.clone())),
+                (Some(value), Some(bound), Some(Hint::TooLow)) if value < bound => return Some(Guard::TooLow(r.answer.clone())),
+                _ => {}
+            }
+        }
+
+        None
+    }
+
+    pub fn record(&mut self, day: usize, answers: &Answers) {
+        let recorded = self.days.entry(key(day)).or_default();
+
+        for (part, slot, accepted) in [(Part::One, &mut recorded.part1, &mut recorded.accepted_part1), (Part::Two, &mut recorded.part2, &mut recorded.accepted_part2)] {
+            match answers.get(part) {
+                Some(Answer::Unsolved) | None => {},
+                Some(answer) => {
+                    let answer = answer.to_string();
+
+                    // a different answer hasn't been confirmed by the server
+                    if slot.as_ref() != Some(&answer) {
+                        *accepted = false;
+                    }
+
+                    *slot = Some(answer);
+                }
+            }
+        }
+    }
+
+    pub fn verify(&self, day: usize, part: Part, actual: &Answer) -> Status {
+        match self.get(day, part) {
+            None => Status::Missing,
+            Some(expected) if actual != &Answer::Unsolved && expected == actual.to_string() => Status::Pass,
+            Some(_) => Status::Fail
+        }
+    }
+}
+
+impl std::fmt::Display for AnswerFile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&toml::to_string(&self.days).expect("Couldn't serialize answers"))
+    }
+}
+
+impl std::fmt::Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Status::Pass => "pass",
+            Status::Fail => "FAIL",
+            Status::Missing => "missing"
+        })
+    }
+}
+
+impl std::fmt::Display for Hint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Hint::TooHigh => "too high",
+            Hint::TooLow => "too low"
+        })
+    }
+}
+
+impl std::fmt::Display for Guard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Guard::Accepted(answer) => write!(f, "the answer {answer} was already accepted"),
+            Guard::Rejected => write!(f, "it was already rejected"),
+            Guard::TooHigh(bound) => write!(f, "{bound} was already too high"),
+            Guard::TooLow(bound) => write!(f, "{bound} was already too low")
+        }
+    }
+}
+
+fn key(day: usize) -> String {
+    format!("day_{:0>2}", day)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use common::{Answer, Answers, Part};
+
+    use crate::answers::*;
+
+    #[test]
+    fn test_parse() {
+        let file = AnswerFile::parse(EXAMPLE_FILE).unwrap();
+
+        assert_eq!(file.get(1, Part::One), Some("142"));
+        assert_eq!(file.get(1, Part::Two), Some("281"));
+        assert_eq!(file.get(25, Part::One), Some("54"));
+        assert_eq!(file.get(25, Part::Two), None);
+        assert_eq!(file.get(2, Part::One), None);
+    }
+
+    #[test]
+    fn test_verify() {
+        let file = AnswerFile::parse(EXAMPLE_FILE).unwrap();
+
+        assert_eq!(file.verify(1, Part::One, &Answer::Integer(142)), Status::Pass);
+        assert_eq!(file.verify(1, Part::Two, &Answer::Integer(280)), Status::Fail);
+        assert_eq!(file.verify(1, Part::Two, &Answer::Unsolved), Status::Fail);
+        assert_eq!(file.verify(3, Part::One, &Answer::Integer(1)), Status::Missing);
+    }
+
+    #[test]
+    fn test_record() {
+        let mut file = AnswerFile::default();
+        file.record(1, &Answers { part1: Some(Answer::Integer(142)), part2: Some(Answer::Integer(281)) });
+        file.record(25, &Answers { part1: Some(Answer::Integer(54)), part2: Some(Answer::Unsolved) });
+
+        assert_eq!(file.to_string(), EXAMPLE_FILE);
+
+        file.record(1, &Answers { part1: None, part2: Some(Answer::Integer(282)) });
+
+        assert_eq!(file.get(1, Part::One), Some("142"));
+        assert_eq!(file.get(1, Part::Two), Some("282"));
+    }
+
+    #[test]
+    fn test_accept() {
+        let mut file = AnswerFile::default();
+        file.accept(3, Part::Two, "abc");
+
+        assert_eq!(file.get(3, Part::Two), Some("abc"));
+        assert_eq!(file.get(3, Part::One), None);
+    }
+
+    #[test]
+    fn test_guard() {
+        let mut file = AnswerFile::parse(EXAMPLE_FILE).unwrap();
+        file.reject(2, Part::One, "100", Some(Hint::TooHigh));
+        file.reject(2, Part::One, "10", Some(Hint::TooLow));
+        file.reject(2, Part::One, "50", None);
+        file.reject(2, Part::One, "50", None);
+
+        assert_eq!(file.guard(1, Part::One, "142"), None);
+        assert_eq!(file.guard(2, Part::One, "50"), Some(Guard::Rejected));
+        assert_eq!(file.guard(2, Part::One, "101"), Some(Guard::TooHigh("100".to_owned())));
+        assert_eq!(file.guard(2, Part::One, "9"), Some(Guard::TooLow("10".to_owned())));
+        assert_eq!(file.guard(2, Part::One, "51"), None);
+        assert_eq!(file.guard(2, Part::Two, "50"), None);
+        assert_eq!(file.guard(3, Part::One, "50"), None);
+    }
+
+    #[test]
+    fn test_record_then_submit() {
+        let mut file = AnswerFile::default();
+        file.record(5, &Answers { part1: Some(Answer::Integer(35)), part2: None });
+
+        assert_eq!(file.guard(5, Part::One, "35"), None);
+
+        file.accept(5, Part::One, "35");
+        let parsed = AnswerFile::parse(&file.to_string()).unwrap();
+
+        assert_eq!(parsed.guard(5, Part::One, "35"), Some(Guard::Accepted("35".to_owned())));
+        assert_eq!(parsed.guard(5, Part::Two, "46"), None);
+
+        // a run that changes the answer doesn't inherit the acceptance
+        file.record(5, &Answers { part1: Some(Answer::Integer(36)), part2: None });
+
+        assert_eq!(file.guard(5, Part::One, "36"), None);
+        assert_eq!(file.get(5, Part::One), Some("36"));
+    }
+
+    #[test]
+    fn test_rejected_round_trip() {
+        let mut file = AnswerFile::default();
+        file.reject(4, Part::Two, "7", Some(Hint::TooLow));
+        file.reject(4, Part::Two, "x", None);
+
+        let parsed = AnswerFile::parse(&file.to_string()).unwrap();
+
+        assert_eq!(parsed, file);
+        assert_eq!(parsed.guard(4, Part::Two, "6"), Some(Guard::TooLow("7".to_owned())));
+    }
+
+    #[test]
+    fn test_invalid_file() {
+        assert!(matches!(AnswerFile::parse("[day_01]\npart1 = "), Err(RunnerError::InvalidAnswers(_))));
+    }
+
+    #[test]
+    fn test_load_and_save() {
+        let dir = env::temp_dir().join(format!("runner-test-answers-{}", std::process::id()));
+        let path = dir.join("2023").join("answers.toml");
+
+        assert_eq!(AnswerFile::load(&path).unwrap(), AnswerFile::default());
+
+        let file = AnswerFile::parse(EXAMPLE_FILE).unwrap();
+        file.save(&path).unwrap();
+
+        assert_eq!(AnswerFile::load(&path).unwrap(), file);
+        assert!(matches!(AnswerFile::load(&dir), Err(RunnerError::AnswersRead { .. })));
+        assert!(matches!(file.save(path.join("answers.toml")), Err(RunnerError::AnswersWrite { .. })));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    const EXAMPLE_FILE: &str = "[day_01]
+part1 = \"142\"
+part2 = \"281\"
+
+[day_25]
+part1 = \"54\"
+";
+}
@@ -0,0 +1,74 @@
+use std::time::Duration;
+
+use common::{Part, Run};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let min = *sorted.first()?;
+        let median = (sorted[(sorted.len() - 1) / 2] + sorted[sorted.len() / 2]) / 2;
+        let mean = sorted.iter().sum::<Duration>() / sorted.len() as u32;
+
+        Some(Stats { min, median, mean })
+    }
+}
+
+pub fn bench<F: Fn() -> Run>(solve: F, iterations: usize) -> Vec<(&'static str, Stats)> {
+    let runs: Vec<Run> = (0..iterations).map(|_| solve()).collect();
+    let mut result = Vec::new();
+
+    let parse: Vec<Duration> = runs.iter().map(|r| r.timings.parse).collect();
+    result.extend(Stats::from_samples(&parse).map(|s| ("parse", s)));
+
+    for (name, part) in [("part 1", Part::One), ("part 2", Part::Two)] {
+        let samples: Vec<Duration> = runs.iter().filter_map(|r| r.timings.get(part)).collect();
+        result.extend(Stats::from_samples(&samples).map(|s| (name, s)));
+    }
+
+    let total: Vec<Duration> = runs.iter().map(|r| r.timings.total()).collect();
+    result.extend(Stats::from_samples(&total).map(|s| ("total", s)));
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use common::{Answers, Timings};
+
+    use crate::bench::*;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn test_stats() {
+        assert_eq!(Stats::from_samples(&[ms(5), ms(1), ms(3)]), Some(Stats { min: ms(1), median: ms(3), mean: ms(3) }));
+        assert_eq!(Stats::from_samples(&[ms(8), ms(1), ms(2), ms(1)]), Some(Stats { min: ms(1), median: Duration::from_micros(1500), mean: ms(3) }));
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn test_bench() {
+        let run = || Run {
+            answers: Answers { part1: Some(1.into()), part2: None },
+            timings: Timings { parse: ms(1), part1: Some(ms(2)), part2: None }
+        };
+        let result = bench(run, 3);
+        let stages: Vec<&str> = result.iter().map(|(name, _)| *name).collect();
+
+        assert_eq!(stages, vec!["parse", "part 1", "total"]);
+        assert_eq!(result[2].1, Stats { min: ms(3), median: ms(3), mean: ms(3) });
+    }
+}
@@ -0,0 +1,469 @@
+use std::path::PathBuf;
+
+use common::{Part, Style};
+
+pub const USAGE: &str = "Usage: ./runner [bench|verify|record|submit|watch|list] DAYS [PART] [--year YEAR] [--part 1|2] [--iterations N] [--jobs N] [--input PATH|-] [--example N] [--format text|json|csv] [--visualize plain|ansi]
+
+DAYS is a single day (`5`), a range (`3-7`), a comma-separated list (`5,12,19`) or `all`.
+`--jobs` solves up to N days concurrently when solving, verifying or recording.
+`--year` selects the event, defaulting to the YEAR environment variable and then to the latest event that has started.
+`--input` reads the puzzle input of a single day from PATH (or stdin for `-`) instead of input/YEAR/day_XX.txt.
+`--example` solves the Nth example from the puzzle description, cached in input/YEAR/day_XX.example.N.txt.
+`--format` selects machine-readable output for solving and verifying.
+`--visualize` also prints how each part of a single day was solved, with ANSI colours for `ansi`, if the day supports it.
+`bench` solves each day N times (10 by default) and reports min/median/mean timings.
+`verify` compares the answers with the ones recorded in input/YEAR/answers.toml.
+`record` saves the answers to input/YEAR/answers.toml.
+`submit` posts the answer to PART of a single day, recording it in input/YEAR/answers.toml if it's correct.
+Rejected answers are recorded too and are never submitted again.
+`watch` rebuilds and re-solves a single day and runs its tests whenever its sources or input change.
+`list` shows the puzzles of YEAR and which of them are implemented.
+`new-day DAY` creates the crate for DAY of YEAR and adds it to the workspace and the runner's dependencies.
+`init YEAR` sets up .cargo/config.toml and creates the crates for all days of YEAR.
+Requests to adventofcode.com are throttled across runs, set USER_AGENT to override the default User-Agent.";
+
+const DEFAULT_ITERATIONS: usize = 10;
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum Command {
+    Solve,
+    Bench { iterations: usize },
+    Verify,
+    Record,
+    Submit,
+    NewDay,
+    Init,
+    List,
+    Watch
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct Options {
+    pub command: Command,
+    pub days: Vec<usize>,
+    pub year: Option<i32>,
+    pub part: Option<Part>,
+    pub input: Option<InputSource>,
+    pub example: Option<usize>,
+    pub format: Format,
+    pub jobs: usize,
+    pub visualize: Option<Style>
+}
+
+impl Options {
+    pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Self, String> {
+        let mut args = args.into_iter().peekable();
+        let mut days = None;
+        let mut year = None;
+        let mut part = None;
+        let mut iterations = None;
+        let mut input = None;
+        let mut example = None;
+        let mut format = None;
+        let mut jobs = None;
+        let mut visualize = None;
+
+        let command = args.next_if(|arg| ["bench", "verify", "record", "submit", "new-day", "init", "list", "watch"].contains(&arg.as_str()));
+        let is_bench = command.as_deref() == Some("bench");
+        let is_submit = command.as_deref() == Some("submit");
+        let is_init = command.as_deref() == Some("init");
+
+        while let Some(arg) = args.next() {
+            match arg.as_ref() {
+                "--year" => {
+                    let value = args.next().ok_or("--year requires a value")?;
+                    year = Some(parse_year(&value)?);
+                },
+                "--part" => {
+                    let value = args.next().ok_or("--part requires a value")?;
+                    part = Some(parse_part(&value)?);
+                },
+                "--iterations" if is_bench => {
+                    let value = args.next().ok_or("--iterations requires a value")?;
+                    iterations = Some(parse_iterations(&value)?);
+                },
+                "--jobs" => {
+                    let value = args.next().ok_or("--jobs requires a value")?;
+                    jobs = Some(parse_jobs(&value)?);
+                },
+                "--input" => {
+                    let value = args.next().ok_or("--input requires a value")?;
+                    input = Some(parse_input_source(&value));
+                },
+                "--example" => {
+                    let value = args.next().ok_or("--example requires a value")?;
+                    example = Some(parse_example(&value)?);
+                },
+                "--format" => {
+                    let value = args.next().ok_or("--format requires a value")?;
+                    format = Some(parse_format(&value)?);
+                },
+                "--visualize" => {
+                    let value = args.next().ok_or("--visualize requires a value")?;
+                    visualize = Some(parse_style(&value)?);
+                },
+                _ if arg.starts_with("--") => return Err(format!("Unknown option: {arg}")),
+                _ if is_init && year.is_none() => year = Some(parse_year(&arg)?),
+                _ if days.is_none() && !is_init => days = Some(parse_days(&arg)?),
+                _ if is_submit && part.is_none() => part = Some(parse_part(&arg)?),
+                _ => return Err(format!("Unexpected argument: {arg}"))
+            }
+        }
+
+        let command = match command.as_deref() {
+            Some("bench") => Command::Bench { iterations: iterations.unwrap_or(DEFAULT_ITERATIONS) },
+            Some("verify") => Command::Verify,
+            Some("record") => Command::Record,
+            Some("submit") => Command::Submit,
+            Some("new-day") => Command::NewDay,
+            Some("init") => Command::Init,
+            Some("list") => Command::List,
+            Some("watch") => Command::Watch,
+            _ => Command::Solve
+        };
+
+        if matches!(command, Command::NewDay | Command::Init) {
+            if part.is_some() || input.is_some() || example.is_some() || format.is_some() {
+                return Err("new-day and init only accept --year".to_owned());
+            }
+
+            if command == Command::Init && year.is_none() {
+                return Err("init requires a YEAR".to_owned());
+            }
+
+            if command == Command::NewDay && days.as_ref().is_some_and(|days| days.len() != 1) {
+                return Err("new-day requires a single day".to_owned());
+            }
+        }
+
+        if command == Command::List && (days.is_some() || part.is_some() || input.is_some() || example.is_some() || format.is_some()) {
+            return Err("list only accepts --year".to_owned());
+        }
+
+        let days = if matches!(command, Command::Init | Command::List) { Vec::new() } else { days.ok_or("Missing DAYS argument")? };
+
+        if input.is_some() {
+            if days.len() != 1 {
+                return Err("--input can only be used with a single day".to_owned());
+            }
+
+            if matches!(command, Command::Verify | Command::Record | Command::Submit) {
+                return Err("--input can't be used with recorded answers".to_owned());
+            }
+        }
+
+        if example.is_some() {
+            if input.is_some() {
+                return Err("--example and --input can't be used together".to_owned());
+            }
+
+            if matches!(command, Command::Verify | Command::Record | Command::Submit) {
+                return Err("--example can't be used with recorded answers".to_owned());
+            }
+        }
+
+        if command == Command::Watch {
+            if days.len() != 1 {
+                return Err("watch requires a single day".to_owned());
+            }
+
+            if input.is_some() {
+                return Err("--input can't be used with watch".to_owned());
+            }
+        }
+
+        if command == Command::Submit {
+            if days.len() != 1 {
+                return Err("submit requires a single day".to_owned());
+            }
+
+            if part.is_none() {
+                return Err("submit requires a PART".to_owned());
+            }
+        }
+
+        if jobs.is_some() && !matches!(command, Command::Solve | Command::Verify | Command::Record) {
+            return Err("--jobs can only be used when solving, verifying or recording".to_owned());
+        }
+
+        if format.is_some() && !matches!(command, Command::Solve | Command::Verify) {
+            return Err("--format can only be used when solving or verifying".to_owned());
+        }
+
+        if visualize.is_some() {
+            if command != Command::Solve || days.len() != 1 {
+                return Err("--visualize can only be used when solving a single day".to_owned());
+            }
+
+            if format.is_some_and(|format| format != Format::Text) {
+                return Err("--visualize can only be used with text output".to_owned());
+            }
+        }
+
+        Ok(Options { command, days, year, part, input, example, format: format.unwrap_or_default(), jobs: jobs.unwrap_or(1), visualize })
+    }
+}
+
+fn parse_format(s: &str) -> Result<Format, String> {
+    match s {
+        "text" => Ok(Format::Text),
+        "json" => Ok(Format::Json),
+        "csv" => Ok(Format::Csv),
+        _ => Err("FORMAT must be one of text, json, csv".to_owned())
+    }
+}
+
+fn parse_style(s: &str) -> Result<Style, String> {
+    match s {
+        "plain" => Ok(Style::Plain),
+        "ansi" => Ok(Style::Ansi),
+        _ => Err("STYLE must be one of plain, ansi".to_owned())
+    }
+}
+
+fn parse_jobs(s: &str) -> Result<usize, String> {
+    match s.parse::<usize>() {
+        Ok(n) if n > 0 => Ok(n),
+        _ => Err("Number of jobs must be a positive integer".to_owned())
+    }
+}
+
+fn parse_example(s: &str) -> Result<usize, String> {
+    match s.parse::<usize>() {
+        Ok(n) if n > 0 => Ok(n),
+        _ => Err("Example number must be a positive integer".to_owned())
+    }
+}
+
+fn parse_input_source(s: &str) -> InputSource {
+    if s == "-" {
+        InputSource::Stdin
+    } else {
+        InputSource::File(PathBuf::from(s))
+    }
+}
+
+pub fn parse_days(s: &str) -> Result<Vec<usize>, String> {
+    if s == "all" {
+        return Ok((1..=25).collect());
+    }
+
+    let mut days = Vec::new();
+
+    for item in s.split(',') {
+        match item.split_once('-') {
+            Some((from, to)) => {
+                let from = parse_day(from)?;
+                let to = parse_day(to)?;
+
+                if from > to {
+                    return Err(format!("Invalid range: {item}"));
+                }
+
+                days.extend(from..=to);
+            },
+            None => days.push(parse_day(item)?)
+        }
+    }
+
+    days.sort();
+    days.dedup();
+
+    Ok(days)
+}
+
+fn parse_day(s: &str) -> Result<usize, String> {
+    match s.parse::<usize>() {
+        Ok(n) if (1..=25).contains(&n) => Ok(n),
+        Ok(_) => Err("DAY must be in range [1,25]".to_owned()),
+        Err(_) => Err("DAY must be an integer".to_owned())
+    }
+}
+
+fn parse_year(s: &str) -> Result<i32, String> {
+    match s.parse::<i32>() {
+        Ok(n) if n >= 2015 => Ok(n),
+        Ok(_) => Err("YEAR must be 2015 or later".to_owned()),
+        Err(_) => Err("YEAR must be an integer".to_owned())
+    }
+}
+
+fn parse_part(s: &str) -> Result<Part, String> {
+    match s {
+        "1" => Ok(Part::One),
+        "2" => Ok(Part::Two),
+        _ => Err("PART must be 1 or 2".to_owned())
+    }
+}
+
+fn parse_iterations(s: &str) -> Result<usize, String> {
+    match s.parse::<usize>() {
+        Ok(n) if n > 0 => Ok(n),
+        _ => Err("Number of iterations must be a positive integer".to_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cli::*;
+
+    fn args(s: &str) -> Vec<String> {
+        s.split_whitespace().map(str::to_owned).collect()
+    }
+
+    #[test]
+    fn test_parse_days() {
+        assert_eq!(parse_days("5"), Ok(vec![5]));
+        assert_eq!(parse_days("3-7"), Ok(vec![3, 4, 5, 6, 7]));
+        assert_eq!(parse_days("19,5,12"), Ok(vec![5, 12, 19]));
+        assert_eq!(parse_days("1-3,2,25"), Ok(vec![1, 2, 3, 25]));
+        assert_eq!(parse_days("all"), Ok((1..=25).collect()));
+    }
+
+    #[test]
+    fn test_parse_days_invalid() {
+        assert!(parse_days("0").is_err());
+        assert!(parse_days("26").is_err());
+        assert!(parse_days("7-3").is_err());
+        assert!(parse_days("x").is_err());
+        assert!(parse_days("1,").is_err());
+    }
+
+    #[test]
+    fn test_parse_options() {
+        assert_eq!(Options::parse(args("all --part 2")), Ok(Options { command: Command::Solve, days: (1..=25).collect(), year: None, part: Some(Part::Two), input: None, example: None, format: Format::Text, jobs: 1, visualize: None }));
+        assert_eq!(Options::parse(args("--part 1 4")), Ok(Options { command: Command::Solve, days: vec![4], year: None, part: Some(Part::One), input: None, example: None, format: Format::Text, jobs: 1, visualize: None }));
+        assert_eq!(Options::parse(args("4")), Ok(Options { command: Command::Solve, days: vec![4], year: None, part: None, input: None, example: None, format: Format::Text, jobs: 1, visualize: None }));
+    }
+
+    #[test]
+    fn test_parse_bench_options() {
+        assert_eq!(Options::parse(args("bench 1-2")), Ok(Options { command: Command::Bench { iterations: 10 }, days: vec![1, 2], year: None, part: None, input: None, example: None, format: Format::Text, jobs: 1, visualize: None }));
+        assert_eq!(Options::parse(args("bench 3 --iterations 50")), Ok(Options { command: Command::Bench { iterations: 50 }, days: vec![3], year: None, part: None, input: None, example: None, format: Format::Text, jobs: 1, visualize: None }));
+    }
+
+    #[test]
+    fn test_parse_verify_options() {
+        assert_eq!(Options::parse(args("verify all")), Ok(Options { command: Command::Verify, days: (1..=25).collect(), year: None, part: None, input: None, example: None, format: Format::Text, jobs: 1, visualize: None }));
+        assert_eq!(Options::parse(args("record 2 --part 1")), Ok(Options { command: Command::Record, days: vec![2], year: None, part: Some(Part::One), input: None, example: None, format: Format::Text, jobs: 1, visualize: None }));
+    }
+
+    #[test]
+    fn test_parse_input_options() {
+        assert_eq!(Options::parse(args("5 --input example.txt")), Ok(Options { command: Command::Solve, days: vec![5], year: None, part: None, input: Some(InputSource::File("example.txt".into())), example: None, format: Format::Text, jobs: 1, visualize: None }));
+        assert_eq!(Options::parse(args("bench 5 --input -")), Ok(Options { command: Command::Bench { iterations: 10 }, days: vec![5], year: None, part: None, input: Some(InputSource::Stdin), example: None, format: Format::Text, jobs: 1, visualize: None }));
+    }
+
+    #[test]
+    fn test_parse_submit_options() {
+        assert_eq!(Options::parse(args("submit 5 2")), Ok(Options { command: Command::Submit, days: vec![5], year: None, part: Some(Part::Two), input: None, example: None, format: Format::Text, jobs: 1, visualize: None }));
+        assert_eq!(Options::parse(args("submit 5 --part 1 --year 2022")), Ok(Options { command: Command::Submit, days: vec![5], year: Some(2022), part: Some(Part::One), input: None, example: None, format: Format::Text, jobs: 1, visualize: None }));
+    }
+
+    #[test]
+    fn test_parse_watch_options() {
+        assert_eq!(Options::parse(args("watch 12 --example 1")), Ok(Options { command: Command::Watch, days: vec![12], year: None, part: None, input: None, example: Some(1), format: Format::Text, jobs: 1, visualize: None }));
+    }
+
+    #[test]
+    fn test_parse_scaffold_options() {
+        assert_eq!(Options::parse(args("new-day 7 --year 2024")), Ok(Options { command: Command::NewDay, days: vec![7], year: Some(2024), part: None, input: None, example: None, format: Format::Text, jobs: 1, visualize: None }));
+        assert_eq!(Options::parse(args("list --year 2023")), Ok(Options { command: Command::List, days: vec![], year: Some(2023), part: None, input: None, example: None, format: Format::Text, jobs: 1, visualize: None }));
+        assert_eq!(Options::parse(args("init 2024")), Ok(Options { command: Command::Init, days: vec![], year: Some(2024), part: None, input: None, example: None, format: Format::Text, jobs: 1, visualize: None }));
+    }
+
+    #[test]
+    fn test_parse_year_options() {
+        assert_eq!(Options::parse(args("5 --year 2022")).map(|o| o.year), Ok(Some(2022)));
+        assert_eq!(Options::parse(args("verify --year 2015 all")).map(|o| o.year), Ok(Some(2015)));
+    }
+
+    #[test]
+    fn test_parse_example_options() {
+        assert_eq!(Options::parse(args("8 --example 2")).map(|o| o.example), Ok(Some(2)));
+        assert_eq!(Options::parse(args("bench 1-3 --example 1")).map(|o| o.example), Ok(Some(1)));
+    }
+
+    #[test]
+    fn test_parse_jobs_options() {
+        assert_eq!(Options::parse(args("all --jobs 4")).map(|o| o.jobs), Ok(4));
+        assert_eq!(Options::parse(args("verify 1-5 --jobs 2")).map(|o| o.jobs), Ok(2));
+        assert_eq!(Options::parse(args("record 3")).map(|o| o.jobs), Ok(1));
+    }
+
+    #[test]
+    fn test_parse_format_options() {
+        assert_eq!(Options::parse(args("all --format json")).map(|o| o.format), Ok(Format::Json));
+        assert_eq!(Options::parse(args("verify 1-3 --format csv")).map(|o| o.format), Ok(Format::Csv));
+    }
+
+    #[test]
+    fn test_parse_visualize_options() {
+        assert_eq!(Options::parse(args("17 --visualize ansi")).map(|o| o.visualize), Ok(Some(Style::Ansi)));
+        assert_eq!(Options::parse(args("17 --part 2 --visualize plain --format text")).map(|o| o.visualize), Ok(Some(Style::Plain)));
+        assert_eq!(Options::parse(args("17")).map(|o| o.visualize), Ok(None));
+    }
+
+    #[test]
+    fn test_parse_options_invalid() {
+        assert!(Options::parse(args("")).is_err());
+        assert!(Options::parse(args("4 5")).is_err());
+        assert!(Options::parse(args("4 --part")).is_err());
+        assert!(Options::parse(args("4 --part 3")).is_err());
+        assert!(Options::parse(args("4 --verbose")).is_err());
+        assert!(Options::parse(args("4 --iterations 5")).is_err());
+        assert!(Options::parse(args("bench 4 --iterations 0")).is_err());
+        assert!(Options::parse(args("verify 4 --iterations 3")).is_err());
+        assert!(Options::parse(args("4 --input")).is_err());
+        assert!(Options::parse(args("4-5 --input -")).is_err());
+        assert!(Options::parse(args("verify 4 --input -")).is_err());
+        assert!(Options::parse(args("4 --format xml")).is_err());
+        assert!(Options::parse(args("submit 4")).is_err());
+        assert!(Options::parse(args("submit 4-5 1")).is_err());
+        assert!(Options::parse(args("submit 4 3")).is_err());
+        assert!(Options::parse(args("submit 4 1 2")).is_err());
+        assert!(Options::parse(args("submit 4 1 --input -")).is_err());
+        assert!(Options::parse(args("submit 4 1 --format json")).is_err());
+        assert!(Options::parse(args("4 --example 0")).is_err());
+        assert!(Options::parse(args("4 --example")).is_err());
+        assert!(Options::parse(args("4 --example 1 --input -")).is_err());
+        assert!(Options::parse(args("verify 4 --example 1")).is_err());
+        assert!(Options::parse(args("new-day 1-2")).is_err());
+        assert!(Options::parse(args("new-day")).is_err());
+        assert!(Options::parse(args("new-day 3 --part 1")).is_err());
+        assert!(Options::parse(args("init")).is_err());
+        assert!(Options::parse(args("list 5")).is_err());
+        assert!(Options::parse(args("watch 1-3")).is_err());
+        assert!(Options::parse(args("watch 3 --input x.txt")).is_err());
+        assert!(Options::parse(args("watch 3 --format json")).is_err());
+        assert!(Options::parse(args("init 2024 5")).is_err());
+        assert!(Options::parse(args("init 2024 --format json")).is_err());
+        assert!(Options::parse(args("all --jobs 0")).is_err());
+        assert!(Options::parse(args("all --jobs")).is_err());
+        assert!(Options::parse(args("bench all --jobs 4")).is_err());
+        assert!(Options::parse(args("submit 4 1 --jobs 2")).is_err());
+        assert!(Options::parse(args("4 --year")).is_err());
+        assert!(Options::parse(args("4 --year 2014")).is_err());
+        assert!(Options::parse(args("4 --year next")).is_err());
+        assert!(Options::parse(args("bench 4 --format json")).is_err());
+        assert!(Options::parse(args("4 --visualize")).is_err());
+        assert!(Options::parse(args("4 --visualize color")).is_err());
+        assert!(Options::parse(args("4-5 --visualize plain")).is_err());
+        assert!(Options::parse(args("verify 4 --visualize plain")).is_err());
+        assert!(Options::parse(args("4 --visualize plain --format json")).is_err());
+    }
+}
@@ -0,0 +1,225 @@
+use std::{env, fs, thread};
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use reqwest::blocking::{RequestBuilder, Response};
+use reqwest::StatusCode;
+
+use crate::error::RunnerError;
+
+pub const LAST_REQUEST_PATH: &str = "input/.last_request";
+
+const DEFAULT_USER_AGENT: &str = concat!("runner/", env!("CARGO_PKG_VERSION"), " (+https://github.com/ksaweryr/Advent-Of-Code-2023)");
+
+#[derive(Clone, Debug)]
+pub struct ClientConfig {
+    pub user_agent: String,
+    pub timeout: Duration,
+    pub retries: u32,
+    pub backoff: Duration,
+    pub min_interval: Duration,
+    pub last_request_path: PathBuf
+}
+
+impl Default for ClientConfig {
+    fn default() -> Self {
+        ClientConfig {
+            user_agent: DEFAULT_USER_AGENT.to_owned(),
+            timeout: Duration::from_secs(10),
+            retries: 3,
+            backoff: Duration::from_secs(1),
+            min_interval: Duration::from_secs(5),
+            last_request_path: PathBuf::from(LAST_REQUEST_PATH)
+        }
+    }
+}
+
+impl ClientConfig {
+    pub fn from_env() -> Self {
+        let mut config = ClientConfig::default();
+
+        if let Some(user_agent) = env::var("USER_AGENT").ok().filter(|s| !s.is_empty()) {
+            config.user_agent = user_agent;
+        }
+
+        config
+    }
+}
+
+pub struct Client {
+    http: reqwest::blocking::Client,
+    base_url: String,
+    config: ClientConfig
+}
+
+impl Client {
+    pub fn new(base_url: &str, config: ClientConfig) -> Result<Self, RunnerError> {
+        let http = reqwest::blocking::Client::builder()
+            .user_agent(&config.user_agent)
+            .timeout(config.timeout)
+            .build()?;
+
+        Ok(Client { http, base_url: base_url.to_owned(), config })
+    }
+
+    pub fn get(&self, path: &str, session: &str) -> Result<Response, RunnerError> {
+        self.send(|| self.with_session(self.http.get(self.url(path)), session), true)
+    }
+
+    // a submission that timed out or got an error response may still have been received, so only failed connections are retried
+    pub fn post_form(&self, path: &str, session: &str, form: &[(&str, &str)]) -> Result<Response, RunnerError> {
+        self.send(|| self.with_session(self.http.post(self.url(path)), session).form(form), false)
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{path}", self.base_url)
+    }
+
+    fn with_session(&self, request: RequestBuilder, session: &str) -> RequestBuilder {
+        request.header("Cookie", format!("session={session}"))
+    }
+
+    fn send<F: Fn() -> RequestBuilder>(&self, request: F, idempotent: bool) -> Result<Response, RunnerError> {
+        let mut attempt = 0;
+
+        loop {
+            self.throttle();
+
+            let result = request().send();
+            let retryable = match &result {
+                Ok(response) => idempotent && (response.status().is_server_error() || response.status() == StatusCode::TOO_MANY_REQUESTS),
+                Err(e) => e.is_connect() || (idempotent && e.is_timeout())
+            };
+
+            if !retryable || attempt == self.config.retries {
+                return Ok(result?);
+            }
+
+            thread::sleep(self.config.backoff * 2u32.pow(attempt));
+            attempt += 1;
+        }
+    }
+
+    fn throttle(&self) {
+        let path = &self.config.last_request_path;
+        let last = fs::read_to_string(path).ok()
+            .and_then(|s| s.trim().parse::<u64>().ok())
+            .map(|millis| UNIX_EPOCH + Duration::from_millis(millis));
+
+        if let Some(last) = last {
+            let elapsed = SystemTime::now().duration_since(last).unwrap_or_default();
+
+            if let Some(wait) = self.config.min_interval.checked_sub(elapsed) {
+                thread::sleep(wait);
+            }
+        }
+
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_millis();
+
+        if let Some(dir) = path.parent() {
+            let _ = fs::create_dir_all(dir);
+        }
+
+        let _ = fs::write(path, now.to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::TcpListener;
+    use std::time::{Duration, Instant};
+    use std::{env, fs, process};
+
+    use reqwest::StatusCode;
+
+    use crate::client::*;
+    use crate::test_server::{serve, serve_sequence};
+
+    fn config(name: &str) -> ClientConfig {
+        ClientConfig {
+            user_agent: "test-agent".to_owned(),
+            timeout: Duration::from_millis(500),
+            retries: 0,
+            backoff: Duration::from_millis(10),
+            min_interval: Duration::ZERO,
+            last_request_path: env::temp_dir().join(format!("runner-test-{name}-{}", process::id()))
+        }
+    }
+
+    #[test]
+    fn test_user_agent() {
+        let (url, server) = serve("200 OK", "");
+        let client = Client::new(&url, config("user-agent")).unwrap();
+
+        client.get("/", "cookie").unwrap();
+
+        assert!(server.join().unwrap().to_lowercase().contains("user-agent: test-agent\r\n"));
+    }
+
+    #[test]
+    fn test_retry() {
+        let (url, server) = serve_sequence(&[("503 Service Unavailable", ""), ("429 Too Many Requests", ""), ("200 OK", "ok")]);
+        let client = Client::new(&url, ClientConfig { retries: 2, ..config("retry") }).unwrap();
+
+        let start = Instant::now();
+        let response = client.get("/input", "cookie").unwrap();
+
+        assert_eq!(response.text().unwrap(), "ok");
+        assert!(start.elapsed() >= Duration::from_millis(30));
+        assert_eq!(server.join().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_retries_exhausted() {
+        let (url, server) = serve_sequence(&[("500 Internal Server Error", ""), ("502 Bad Gateway", "")]);
+        let client = Client::new(&url, ClientConfig { retries: 1, ..config("exhausted") }).unwrap();
+
+        assert_eq!(client.get("/", "cookie").unwrap().status(), StatusCode::BAD_GATEWAY);
+        assert_eq!(server.join().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_no_retry_on_client_error() {
+        let (url, _) = serve_sequence(&[("404 Not Found", "")]);
+        let client = Client::new(&url, ClientConfig { retries: 3, ..config("client-error") }).unwrap();
+
+        assert_eq!(client.get("/", "cookie").unwrap().status(), StatusCode::NOT_FOUND);
+    }
+
+    #[test]
+    fn test_no_retry_on_post_error() {
+        let (url, server) = serve_sequence(&[("503 Service Unavailable", "")]);
+        let client = Client::new(&url, ClientConfig { retries: 3, ..config("post-error") }).unwrap();
+
+        assert_eq!(client.post_form("/answer", "cookie", &[("answer", "42")]).unwrap().status(), StatusCode::SERVICE_UNAVAILABLE);
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("POST /answer"));
+    }
+
+    #[test]
+    fn test_timeout() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let client = Client::new(&url, ClientConfig { timeout: Duration::from_millis(100), ..config("timeout") }).unwrap();
+
+        assert!(matches!(client.get("/", "cookie"), Err(RunnerError::Network(e)) if e.is_timeout()));
+    }
+
+    #[test]
+    fn test_min_interval_persisted() {
+        let config = ClientConfig { min_interval: Duration::from_millis(300), ..config("interval") };
+        let _ = fs::remove_file(&config.last_request_path);
+        let (url, _) = serve_sequence(&[("200 OK", ""), ("200 OK", "")]);
+
+        let start = Instant::now();
+        Client::new(&url, config.clone()).unwrap().get("/", "cookie").unwrap();
+        assert!(start.elapsed() < Duration::from_millis(300));
+
+        Client::new(&url, config.clone()).unwrap().get("/", "cookie").unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(300));
+
+        fs::remove_file(config.last_request_path).unwrap();
+    }
+}
@@ -0,0 +1,56 @@
+use std::{fmt::Display, io};
+
+use reqwest::StatusCode;
+
+#[derive(Debug)]
+pub enum RunnerError {
+    MissingSession,
+    InvalidSession,
+    PuzzleNotAvailable { day: usize, year: i32 },
+    UnexpectedStatus(StatusCode),
+    UnrecognizedResponse,
+    ExampleNotFound { day: usize, year: i32, number: usize, available: usize },
+    Network(reqwest::Error),
+    CacheWrite { path: String, source: io::Error },
+    InputRead { path: String, source: io::Error },
+    AnswersRead { path: String, source: io::Error },
+    AnswersWrite { path: String, source: io::Error },
+    InvalidAnswers(toml::de::Error)
+}
+
+impl Display for RunnerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RunnerError::MissingSession => write!(f, "SESSION is not set, put your session cookie in .cargo/config.toml"),
+            RunnerError::InvalidSession => write!(f, "Invalid session cookie"),
+            RunnerError::PuzzleNotAvailable { day, year } => write!(f, "Puzzle for day {day} of {year} is not available yet"),
+            RunnerError::UnexpectedStatus(status) => write!(f, "Unexpected response from the server: {status}"),
+            RunnerError::UnrecognizedResponse => write!(f, "Couldn't understand the response from the server"),
+            RunnerError::ExampleNotFound { day, year, number, available } => write!(f, "Example {number} not found, the puzzle for day {day} of {year} has {available} examples"),
+            RunnerError::Network(e) => write!(f, "Network error: {e}"),
+            RunnerError::CacheWrite { path, source } => write!(f, "Couldn't save puzzle input to {path}: {source}"),
+            RunnerError::InputRead { path, source } => write!(f, "Couldn't read puzzle input from {path}: {source}"),
+            RunnerError::AnswersRead { path, source } => write!(f, "Couldn't read answers from {path}: {source}"),
+            RunnerError::AnswersWrite { path, source } => write!(f, "Couldn't save answers to {path}: {source}"),
+            RunnerError::InvalidAnswers(e) => write!(f, "Invalid answers file format: {e}")
+        }
+    }
+}
+
+impl std::error::Error for RunnerError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RunnerError::Network(e) => Some(e),
+            RunnerError::CacheWrite { source, .. } | RunnerError::InputRead { source, .. }
+                | RunnerError::AnswersRead { source, .. } | RunnerError::AnswersWrite { source, .. } => Some(source),
+            RunnerError::InvalidAnswers(e) => Some(e),
+            _ => None
+        }
+    }
+}
+
+impl From<reqwest::Error> for RunnerError {
+    fn from(value: reqwest::Error) -> Self {
+        RunnerError::Network(value)
+    }
+}
@@ -0,0 +1,64 @@
+pub fn extract_examples(html: &str) -> Vec<String> {
+    let mut examples = Vec::new();
+    let mut rest = html;
+
+    while let Some((_, block)) = rest.split_once("<pre><code>") {
+        let Some((code, after)) = block.split_once("</code></pre>") else { break };
+
+        examples.push(decode_entities(&strip_tags(code)));
+        rest = after;
+    }
+
+    examples
+}
+
+fn strip_tags(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    let mut in_tag = false;
+
+    for c in s.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => result.push(c),
+            _ => {}
+        }
+    }
+
+    result
+}
+
+fn decode_entities(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::examples::*;
+
+    #[test]
+    fn test_extract_examples() {
+        let examples = extract_examples(include_str!("../fixtures/2023_day_08.html"));
+
+        assert_eq!(examples.len(), 3);
+        assert_eq!(examples[0], "RL\n\nAAA = (BBB, CCC)\nBBB = (DDD, EEE)\nCCC = (ZZZ, GGG)\nDDD = (DDD, DDD)\nEEE = (EEE, EEE)\nGGG = (GGG, GGG)\nZZZ = (ZZZ, ZZZ)\n");
+        assert_eq!(examples[1], "LLR\n\nAAA = (BBB, BBB)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)\n");
+        assert!(examples[2].starts_with("LR\n\n11A = (11B, XXX)\n"));
+    }
+
+    #[test]
+    fn test_extract_examples_markup() {
+        let html = "<p><code>x</code></p><pre><code>a -&gt; <em>b</em>\n&lt;c&amp;d&gt;\n</code></pre><pre><code>unterminated";
+
+        assert_eq!(extract_examples(html), vec!["a -> b\n<c&d>\n"]);
+    }
+
+    #[test]
+    fn test_extract_examples_none() {
+        assert!(extract_examples("<main><article><p>No examples</p></article></main>").is_empty());
+    }
+}
@@ -1,31 +1,408 @@
-use chrono::{Datelike, Utc};
+use chrono::{Datelike, NaiveDate, Utc};
+use common::Part;
 use reqwest::StatusCode;
-use std::{fs, env};
-
-pub fn get_input(day: usize) -> String {
-    fs::read_to_string(format!("input/day_{:0>2}.txt", day))
-        .unwrap_or_else(|_| fetch_input(
-            day,
-            env::var("YEAR").ok().and_then(|s| s.parse::<i32>().ok()).unwrap_or_else(|| Utc::now().year())))
-}
-
-fn fetch_input(day: usize, year: i32) -> String {
-    let url = format!("https://adventofcode.com/{year}/day/{day}/input");
-    let client = reqwest::blocking::Client::new();
-
-    let response = client
-        .get(url)
-        .header("Cookie", format!("session={}", env::var("SESSION").unwrap_or("".to_string())))
-        .send()
-        .expect("Error while sending a request");
-
-    let text = match response.status() {
-        StatusCode::OK => response.text().unwrap(),
-        StatusCode::BAD_REQUEST => panic!("Invalid session cookie"),
-        StatusCode::NOT_FOUND => panic!("Puzzle is not available yet"),
-        x => panic!("Unkwnon error occured: {x}")
+use std::{fs, env, io::{self, Read}, path::Path, sync::OnceLock};
+
+pub mod answers;
+pub mod bench;
+pub mod cli;
+pub mod client;
+pub mod error;
+pub mod examples;
+pub mod parallel;
+pub mod registry;
+pub mod report;
+pub mod scaffold;
+pub mod submit;
+pub mod watch;
+#[cfg(test)]
+mod test_server;
+
+use cli::InputSource;
+use client::{Client, ClientConfig};
+use error::RunnerError;
+use examples::extract_examples;
+use submit::{parse_verdict, Verdict};
+
+pub const AOC_URL: &str = "https://adventofcode.com";
+
+const LEGACY_YEAR: i32 = 2023;
+
+const SESSION_PLACEHOLDER: &str = "YOUR-SESSION-COOKIE-GOES-HERE";
+
+static CLIENT: OnceLock<Client> = OnceLock::new();
+
+pub fn resolve_year(year: Option<i32>) -> i32 {
+    year.or_else(|| env::var("YEAR").ok().and_then(|s| s.parse::<i32>().ok()))
+        .unwrap_or_else(|| event_year(Utc::now().date_naive()))
+}
+
+// the latest event that has started by `date`, each one starts on December 1st
+pub fn event_year(date: NaiveDate) -> i32 {
+    if date.month() == 12 { date.year() } else { date.year() - 1 }
+}
+
+pub fn input_path(year: i32, day: usize) -> String {
+    format!("input/{year}/day_{:0>2}.txt", day)
+}
+
+// inputs used to be cached as input/day_XX.txt, back when every day was a 2023 one
+pub fn legacy_input_path(day: usize) -> String {
+    format!("input/day_{:0>2}.txt", day)
+}
+
+pub fn get_input(year: i32, day: usize) -> Result<String, RunnerError> {
+    let path = input_path(year, day);
+
+    if year == LEGACY_YEAR {
+        migrate_input(&legacy_input_path(day), &path)?;
+    }
+
+    if let Ok(text) = fs::read_to_string(&path) {
+        return Ok(text);
+    }
+
+    let text = fetch_input(client()?, day, year, &session()?)?;
+    save_input(&path, &text)?;
+
+    Ok(text)
+}
+
+pub fn example_path(year: i32, day: usize, number: usize) -> String {
+    format!("input/{year}/day_{:0>2}.example.{number}.txt", day)
+}
+
+pub fn get_example(year: i32, day: usize, number: usize) -> Result<String, RunnerError> {
+    if let Ok(text) = fs::read_to_string(example_path(year, day, number)) {
+        return Ok(text);
+    }
+
+    // all of a day's examples are cached together, so if some are there this one doesn't exist
+    let available = cached_examples(|n| example_path(year, day, n));
+
+    if available > 0 {
+        return Err(RunnerError::ExampleNotFound { day, year, number, available });
+    }
+
+    let examples = fetch_examples(client()?, day, year, &session()?)?;
+
+    for (i, example) in examples.iter().enumerate() {
+        save_input(&example_path(year, day, i + 1), example)?;
+    }
+
+    examples.get(number - 1).cloned()
+        .ok_or(RunnerError::ExampleNotFound { day, year, number, available: examples.len() })
+}
+
+fn cached_examples<F: Fn(usize) -> String>(path: F) -> usize {
+    (1..).take_while(|&n| Path::new(&path(n)).is_file()).count()
+}
+
+pub fn submit_answer(year: i32, day: usize, part: Part, answer: &str) -> Result<Verdict, RunnerError> {
+    post_answer(client()?, day, year, part, answer, &session()?)
+}
+
+fn client() -> Result<&'static Client, RunnerError> {
+    if let Some(client) = CLIENT.get() {
+        return Ok(client);
+    }
+
+    let client = Client::new(AOC_URL, ClientConfig::from_env())?;
+
+    Ok(CLIENT.get_or_init(|| client))
+}
+
+fn session() -> Result<String, RunnerError> {
+    env::var("SESSION").ok()
+        .filter(|s| !s.is_empty() && s != SESSION_PLACEHOLDER)
+        .ok_or(RunnerError::MissingSession)
+}
+
+pub fn read_input(source: &InputSource) -> Result<String, RunnerError> {
+    match source {
+        InputSource::File(path) => fs::read_to_string(path)
+            .map_err(|source| RunnerError::InputRead { path: path.display().to_string(), source }),
+        InputSource::Stdin => read_from(io::stdin().lock(), "stdin")
+    }
+}
+
+fn read_from<R: Read>(mut reader: R, name: &str) -> Result<String, RunnerError> {
+    let mut text = String::new();
+    reader.read_to_string(&mut text).map_err(|source| RunnerError::InputRead { path: name.to_owned(), source })?;
+
+    Ok(text)
+}
+
+fn fetch_input(client: &Client, day: usize, year: i32, session: &str) -> Result<String, RunnerError> {
+    let response = client.get(&format!("/{year}/day/{day}/input"), session)?;
+
+    match response.status() {
+        StatusCode::OK => Ok(response.text()?),
+        x => Err(status_error(x, day, year))
+    }
+}
+
+fn fetch_examples(client: &Client, day: usize, year: i32, session: &str) -> Result<Vec<String>, RunnerError> {
+    let response = client.get(&format!("/{year}/day/{day}"), session)?;
+
+    match response.status() {
+        StatusCode::OK => Ok(extract_examples(&response.text()?)),
+        x => Err(status_error(x, day, year))
+    }
+}
+
+fn post_answer(client: &Client, day: usize, year: i32, part: Part, answer: &str, session: &str) -> Result<Verdict, RunnerError> {
+    let level = match part {
+        Part::One => "1",
+        Part::Two => "2"
     };
 
-    fs::write(format!("input/day_{:0>2}.txt", day), &text).expect("Couldn't save puzzle input to file");
-    text
-}
\ No newline at end of file
+    let response = client.post_form(&format!("/{year}/day/{day}/answer"), session, &[("level", level), ("answer", answer)])?;
+
+    match response.status() {
+        StatusCode::OK => parse_verdict(&response.text()?).ok_or(RunnerError::UnrecognizedResponse),
+        x => Err(status_error(x, day, year))
+    }
+}
+
+fn status_error(status: StatusCode, day: usize, year: i32) -> RunnerError {
+    match status {
+        StatusCode::BAD_REQUEST => RunnerError::InvalidSession,
+        StatusCode::NOT_FOUND => RunnerError::PuzzleNotAvailable { day, year },
+        x => RunnerError::UnexpectedStatus(x)
+    }
+}
+
+// moves an input cached at `legacy` to `path` so it isn't downloaded again
+fn migrate_input(legacy: &str, path: &str) -> Result<(), RunnerError> {
+    if Path::new(path).exists() || !Path::new(legacy).is_file() {
+        return Ok(());
+    }
+
+    let cache_error = |source| RunnerError::CacheWrite { path: path.to_owned(), source };
+
+    if let Some(dir) = Path::new(path).parent() {
+        fs::create_dir_all(dir).map_err(cache_error)?;
+    }
+
+    fs::rename(legacy, path).map_err(cache_error)
+}
+
+fn save_input(path: &str, text: &str) -> Result<(), RunnerError> {
+    let cache_error = |source| RunnerError::CacheWrite { path: path.to_owned(), source };
+
+    if let Some(dir) = Path::new(path).parent() {
+        fs::create_dir_all(dir).map_err(cache_error)?;
+    }
+
+    fs::write(path, text).map_err(cache_error)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::TcpListener;
+    use std::time::Duration;
+
+    use crate::answers::Hint;
+    use crate::test_server::serve;
+    use crate::*;
+
+    fn client(url: &str) -> Client {
+        let config = ClientConfig {
+            retries: 0,
+            min_interval: Duration::ZERO,
+            last_request_path: env::temp_dir().join(format!("runner-test-lib-{}", std::process::id())),
+            ..ClientConfig::default()
+        };
+
+        Client::new(url, config).unwrap()
+    }
+
+    #[test]
+    fn test_fetch_ok() {
+        let (url, server) = serve("200 OK", "1abc2\n");
+
+        assert_eq!(fetch_input(&client(&url), 1, 2023, "cookie").unwrap(), "1abc2\n");
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2023/day/1/input "));
+        assert!(request.to_lowercase().contains("cookie: session=cookie\r\n"));
+    }
+
+    #[test]
+    fn test_fetch_invalid_session() {
+        let (url, _) = serve("400 Bad Request", "");
+
+        assert!(matches!(fetch_input(&client(&url), 1, 2023, "cookie"), Err(RunnerError::InvalidSession)));
+    }
+
+    #[test]
+    fn test_fetch_not_available() {
+        let (url, _) = serve("404 Not Found", "");
+
+        assert!(matches!(fetch_input(&client(&url), 25, 2023, "cookie"), Err(RunnerError::PuzzleNotAvailable { day: 25, year: 2023 })));
+    }
+
+    #[test]
+    fn test_fetch_unexpected_status() {
+        let (url, _) = serve("500 Internal Server Error", "");
+
+        assert!(matches!(fetch_input(&client(&url), 1, 2023, "cookie"), Err(RunnerError::UnexpectedStatus(StatusCode::INTERNAL_SERVER_ERROR))));
+    }
+
+    #[test]
+    fn test_fetch_network_error() {
+        let url = {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            format!("http://{}", listener.local_addr().unwrap())
+        };
+
+        assert!(matches!(fetch_input(&client(&url), 1, 2023, "cookie"), Err(RunnerError::Network(_))));
+    }
+
+    #[test]
+    fn test_fetch_examples() {
+        let (url, server) = serve("200 OK", include_str!("../fixtures/2023_day_08.html"));
+
+        let examples = fetch_examples(&client(&url), 8, 2023, "cookie").unwrap();
+
+        assert_eq!(examples.len(), 3);
+        assert!(server.join().unwrap().starts_with("GET /2023/day/8 "));
+    }
+
+    #[test]
+    fn test_example_path() {
+        assert_eq!(example_path(2023, 8, 2), "input/2023/day_08.example.2.txt");
+    }
+
+    #[test]
+    fn test_cached_examples() {
+        let dir = env::temp_dir().join(format!("runner-test-examples-{}", std::process::id()));
+        let path = |n| dir.join(format!("day_08.example.{n}.txt")).display().to_string();
+        fs::create_dir_all(&dir).unwrap();
+
+        assert_eq!(cached_examples(path), 0);
+
+        for n in [1, 2, 3, 5] {
+            fs::write(path(n), "").unwrap();
+        }
+
+        assert_eq!(cached_examples(path), 3);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_post_answer() {
+        let (url, server) = serve("200 OK", "<article><p>That's the right answer!</p></article>");
+
+        assert_eq!(post_answer(&client(&url), 5, 2023, Part::Two, "1 2", "cookie").unwrap(), Verdict::Correct);
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /2023/day/5/answer "));
+        assert!(request.to_lowercase().contains("cookie: session=cookie\r\n"));
+        assert!(request.ends_with("\r\n\r\nlevel=2&answer=1+2"));
+    }
+
+    #[test]
+    fn test_post_answer_incorrect() {
+        let (url, _) = serve("200 OK", "<article><p>That's not the right answer; your answer is too low.</p></article>");
+
+        assert_eq!(post_answer(&client(&url), 5, 2023, Part::One, "12", "cookie").unwrap(), Verdict::Incorrect(Some(Hint::TooLow)));
+    }
+
+    #[test]
+    fn test_post_answer_unrecognized() {
+        let (url, _) = serve("200 OK", "<html></html>");
+
+        assert!(matches!(post_answer(&client(&url), 5, 2023, Part::One, "12", "cookie"), Err(RunnerError::UnrecognizedResponse)));
+    }
+
+    #[test]
+    fn test_post_answer_invalid_session() {
+        let (url, _) = serve("400 Bad Request", "");
+
+        assert!(matches!(post_answer(&client(&url), 5, 2023, Part::One, "12", "cookie"), Err(RunnerError::InvalidSession)));
+    }
+
+    #[test]
+    fn test_read_input_file() {
+        let path = env::temp_dir().join(format!("runner-test-input-{}", std::process::id()));
+        fs::write(&path, "0 3 6\n").unwrap();
+
+        assert_eq!(read_input(&InputSource::File(path.clone())).unwrap(), "0 3 6\n");
+        fs::remove_file(&path).unwrap();
+        assert!(matches!(read_input(&InputSource::File(path)), Err(RunnerError::InputRead { .. })));
+    }
+
+    #[test]
+    fn test_read_from() {
+        assert_eq!(read_from("a\nb\n".as_bytes(), "stdin").unwrap(), "a\nb\n");
+        assert!(matches!(read_from(&[0xff, 0xfe][..], "stdin"), Err(RunnerError::InputRead { .. })));
+    }
+
+    #[test]
+    fn test_save_input_creates_directory() {
+        let dir = env::temp_dir().join(format!("runner-test-{}", std::process::id()));
+        let path = dir.join("input").join("day_01.txt");
+
+        save_input(path.to_str().unwrap(), "abc").unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "abc");
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_save_input_failure() {
+        let file = env::temp_dir().join(format!("runner-test-file-{}", std::process::id()));
+        fs::write(&file, "").unwrap();
+        let path = file.join("day_01.txt");
+
+        assert!(matches!(save_input(path.to_str().unwrap(), "abc"), Err(RunnerError::CacheWrite { .. })));
+        fs::remove_file(file).unwrap();
+    }
+
+    #[test]
+    fn test_input_path() {
+        assert_eq!(input_path(2023, 7), "input/2023/day_07.txt");
+        assert_eq!(input_path(2015, 25), "input/2015/day_25.txt");
+    }
+
+    #[test]
+    fn test_migrate_input() {
+        let dir = env::temp_dir().join(format!("runner-test-migrate-{}", std::process::id()));
+        let legacy = dir.join("day_05.txt");
+        let path = dir.join("2023").join("day_05.txt");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(&legacy, "seeds: 79 14").unwrap();
+
+        migrate_input(legacy.to_str().unwrap(), path.to_str().unwrap()).unwrap();
+
+        assert!(!legacy.exists());
+        assert_eq!(fs::read_to_string(&path).unwrap(), "seeds: 79 14");
+
+        // an existing cache in the new layout wins
+        fs::write(&legacy, "stale").unwrap();
+        migrate_input(legacy.to_str().unwrap(), path.to_str().unwrap()).unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "seeds: 79 14");
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_legacy_input_path() {
+        assert_eq!(legacy_input_path(7), "input/day_07.txt");
+    }
+
+    #[test]
+    fn test_event_year() {
+        assert_eq!(event_year(NaiveDate::from_ymd_opt(2024, 12, 1).unwrap()), 2024);
+        assert_eq!(event_year(NaiveDate::from_ymd_opt(2024, 12, 31).unwrap()), 2024);
+        assert_eq!(event_year(NaiveDate::from_ymd_opt(2025, 1, 1).unwrap()), 2024);
+        assert_eq!(event_year(NaiveDate::from_ymd_opt(2025, 11, 30).unwrap()), 2024);
+    }
+
+    #[test]
+    fn test_resolve_year_prefers_flag() {
+        assert_eq!(resolve_year(Some(2019)), 2019);
+    }
+}
@@ -1,77 +1,242 @@
-use runner::get_input;
-
-use day_01;
-use day_02;
-use day_03;
-use day_04;
-use day_05;
-use day_06;
-use day_07;
-use day_08;
-use day_09;
-use day_10;
-use day_11;
-use day_12;
-use day_13;
-use day_14;
-use day_15;
-use day_16;
-use day_17;
-use day_18;
-use day_19;
-use day_20;
-use day_21;
-use day_22;
-use day_23;
-use day_24;
-use day_25;
-
-use std::env;
-
-const SOLVE_FUNCTIONS: [fn(String); 25] = [
-     day_01::solve,
-     day_02::solve,
-     day_03::solve,
-     day_04::solve,
-     day_05::solve,
-     day_06::solve,
-     day_07::solve,
-     day_08::solve,
-     day_09::solve,
-     day_10::solve,
-     day_11::solve,
-     day_12::solve,
-     day_13::solve,
-     day_14::solve,
-     day_15::solve,
-     day_16::solve,
-     day_17::solve,
-     day_18::solve,
-     day_19::solve,
-     day_20::solve,
-     day_21::solve,
-     day_22::solve,
-     day_23::solve,
-     day_24::solve,
-     day_25::solve,
-];
+use common::{Answer, Part, Puzzle, Run, Style};
+use runner::answers::{answers_path, AnswerFile, Status};
+use runner::bench::bench;
+use runner::cli::{Command, Format, Options, USAGE};
+use runner::submit::Verdict;
+use runner::{get_example, get_input, read_input, resolve_year, submit_answer};
+use runner::report::{bench_table, csv, json, puzzle_table, records, summary_table, timings_line, verify_table};
+use runner::{parallel, registry, scaffold, watch};
+
+use std::{env, io, path::Path, process};
 
 fn main() {
-    let args: Vec<String> = env::args().collect();
+    let options = match Options::parse(env::args().skip(1)) {
+        Ok(options) => options,
+        Err(msg) => {
+            println!("{msg}");
+            println!("{USAGE}");
+            return;
+        }
+    };
+
+    let year = resolve_year(options.year);
+
+    match options.command {
+        Command::Init => return report_scaffolding(scaffold::init(Path::new("."), year)),
+        Command::NewDay => return report_scaffolding(scaffold::new_day(Path::new("."), year, options.days[0])),
+        Command::Watch => return watch::watch(year, options.days[0], options.part, options.example),
+        _ => {}
+    }
 
-    if args.len() != 2 {
-        println!("Usage: ./{} DAY", args[0]);
+    let years = registry::years();
+
+    if !years.contains(&year) {
+        let years: Vec<_> = years.iter().map(i32::to_string).collect();
+        eprintln!("No solutions for {year}, pick one of the available years with --year or YEAR: {}", years.join(", "));
+        process::exit(1);
+    }
+
+    if options.command == Command::List {
+        let days: Vec<_> = (1..=25).map(|day| (day, registry::find(year, day))).collect();
+        print!("{}", puzzle_table(&days));
         return;
     }
 
-    match args[1].parse::<usize>() {
-        Ok(n) => {
-            if n < 1 || n > 25 {
-                println!("DAY must be in range [1,25]");
-            } else {
-                SOLVE_FUNCTIONS[n - 1](get_input(n));
+    let solutions: Vec<_> = options.days.iter()
+        .filter_map(|&day| {
+            let puzzle = registry::find(year, day);
+
+            if puzzle.is_none() {
+                eprintln!("Day {day} of {year} is not implemented");
             }
+
+            puzzle
+        })
+        .collect();
+
+    if solutions.is_empty() {
+        process::exit(1);
+    }
+
+    match options.command {
+        Command::Solve => solve(year, &solutions, &options),
+        Command::Bench { iterations } => {
+            let results: Vec<_> = solutions.iter()
+                .map(|puzzle| {
+                    let input = input(year, puzzle.day, &options);
+                    (puzzle.day, bench(|| (puzzle.solve)(input.clone(), options.part), iterations))
+                })
+                .collect();
+
+            print!("{}", bench_table(&results));
+        },
+        Command::Verify => verify(year, &solutions, &options),
+        Command::Record => record(year, &solutions, &options),
+        Command::Submit => submit(year, &solutions, &options),
+        Command::Init | Command::NewDay | Command::List | Command::Watch => unreachable!()
+    }
+}
+
+fn report_scaffolding(result: io::Result<Vec<String>>) {
+    match result {
+        Ok(actions) if actions.is_empty() => println!("Nothing to do, everything is already in place"),
+        Ok(actions) => actions.iter().for_each(|action| println!("{action}")),
+        Err(e) => {
+            eprintln!("Couldn't scaffold: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+fn run_days(year: i32, solutions: &[&Puzzle], options: &Options) -> Vec<(usize, Run)> {
+    let inputs: Vec<_> = solutions.iter()
+        .map(|puzzle| (puzzle.day, puzzle.solve, input(year, puzzle.day, options)))
+        .collect();
+
+    parallel::map_ordered(inputs, options.jobs, |(day, solve, input)| (day, solve(input, options.part)))
+}
+
+fn input(year: i32, day: usize, options: &Options) -> String {
+    let input = match (&options.input, options.example) {
+        (Some(source), _) => read_input(source),
+        (None, Some(number)) => get_example(year, day, number),
+        (None, None) => get_input(year, day)
+    };
+
+    input.unwrap_or_else(|e| {
+        eprintln!("Couldn't get input for day {day}: {e}");
+        process::exit(1);
+    })
+}
+
+fn load_answers(path: &str) -> AnswerFile {
+    AnswerFile::load(path).unwrap_or_else(|e| {
+        eprintln!("{e}");
+        process::exit(1);
+    })
+}
+
+fn save_answers(answers: &AnswerFile, path: &str) {
+    if let Err(e) = answers.save(path) {
+        eprintln!("{e}");
+        process::exit(1);
+    }
+}
+
+fn verify(year: i32, solutions: &[&Puzzle], options: &Options) {
+    let expected = load_answers(&answers_path(year));
+    let results = run_days(year, solutions, options);
+    let (table, statuses) = verify_table(&results, &expected);
+    let count = |status| statuses.iter().filter(|&&s| s == status).count();
+
+    match options.format {
+        Format::Text => {
+            print!("{table}");
+            println!("{} passed, {} failed, {} missing", count(Status::Pass), count(Status::Fail), count(Status::Missing));
+        },
+        Format::Json => print!("{}", json(&records(&results, Some(&expected)))),
+        Format::Csv => print!("{}", csv(&records(&results, Some(&expected))))
+    }
+
+    if count(Status::Fail) != 0 {
+        process::exit(1);
+    }
+}
+
+fn record(year: i32, solutions: &[&Puzzle], options: &Options) {
+    let path = answers_path(year);
+    let mut answers = load_answers(&path);
+
+    for (n, run) in run_days(year, solutions, options) {
+        answers.record(n, &run.answers);
+    }
+
+    save_answers(&answers, &path);
+    println!("Saved answers to {path}");
+}
+
+fn submit(year: i32, solutions: &[&Puzzle], options: &Options) {
+    let day = solutions[0].day;
+    let part = options.part.expect("submit requires a part");
+    let number = if part == Part::One { 1 } else { 2 };
+    let run = (solutions[0].solve)(input(year, day, options), Some(part));
+
+    let answer = match run.answers.get(part) {
+        Some(Answer::Unsolved) | None => {
+            eprintln!("Day {day} part {number} is not solved yet");
+            process::exit(1);
+        },
+        Some(answer) => answer.to_string()
+    };
+
+    let path = answers_path(year);
+    let mut answers = load_answers(&path);
+
+    if let Some(guard) = answers.guard(day, part, &answer) {
+        eprintln!("Not submitting {answer} for day {day} part {number}: {guard}");
+        process::exit(1);
+    }
+
+    let verdict = submit_answer(year, day, part, &answer).unwrap_or_else(|e| {
+        eprintln!("Couldn't submit answer for day {day}: {e}");
+        process::exit(1);
+    });
+
+    println!("Day {day} part {number} ({answer}): {verdict}");
+
+    match verdict {
+        Verdict::Correct => answers.accept(day, part, &answer),
+        Verdict::Incorrect(hint) => answers.reject(day, part, &answer, hint),
+        _ => process::exit(1)
+    }
+
+    save_answers(&answers, &path);
+
+    if verdict != Verdict::Correct {
+        process::exit(1);
+    }
+}
+
+fn solve(year: i32, solutions: &[&Puzzle], options: &Options) {
+    // the options only allow this for a single day with text output
+    if let Some(style) = options.visualize {
+        return visualize(year, solutions[0], style, options);
+    }
+
+    let results = run_days(year, solutions, options);
+
+    if options.format == Format::Json {
+        print!("{}", json(&records(&results, None)));
+    } else if options.format == Format::Csv {
+        print!("{}", csv(&records(&results, None)));
+    } else if let [(_, run)] = results.as_slice() {
+        print_run(run);
+    } else {
+        print!("{}", summary_table(&results));
+    }
+}
+
+fn print_run(run: &Run) {
+    for answer in [&run.answers.part1, &run.answers.part2].into_iter().flatten() {
+        if answer != &Answer::Unsolved {
+            println!("{answer}");
+        }
+    }
+
+    eprintln!("{}", timings_line(run));
+}
+
+fn visualize(year: i32, puzzle: &Puzzle, style: Style, options: &Options) {
+    let (run, pictures) = (puzzle.visualize)(input(year, puzzle.day, options), options.part, style);
+
+    print_run(&run);
+
+    for (part, picture) in pictures {
+        let number = if part == Part::One { 1 } else { 2 };
+
+        match picture {
+            Some(picture) => println!("\nPart {number}:\n{picture}"),
+            None => eprintln!("Day {} part {number} has no visualization", puzzle.day)
         }
-        Err(_) => println!("DAY must be an integer")
     }
 }
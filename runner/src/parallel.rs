@@ -0,0 +1,66 @@
+use std::sync::Mutex;
+use std::thread;
+
+pub fn map_ordered<T: Send, R: Send, F: Fn(T) -> R + Sync>(items: Vec<T>, jobs: usize, f: F) -> Vec<R> {
+    let workers = jobs.clamp(1, items.len().max(1));
+    let queue = Mutex::new(items.into_iter().enumerate());
+
+    let mut results: Vec<(usize, R)> = thread::scope(|scope| {
+        let handles: Vec<_> = (0..workers)
+            .map(|_| scope.spawn(|| {
+                let mut done = Vec::new();
+
+                loop {
+                    let next = queue.lock().unwrap().next();
+                    let Some((i, item)) = next else { break };
+                    done.push((i, f(item)));
+                }
+
+                done
+            }))
+            .collect();
+
+        handles.into_iter().flat_map(|handle| handle.join().unwrap()).collect()
+    });
+
+    results.sort_by_key(|(i, _)| *i);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use std::thread;
+    use std::time::Duration;
+
+    use crate::parallel::*;
+
+    #[test]
+    fn test_map_ordered() {
+        let items: Vec<u64> = (0..20).collect();
+
+        let results = map_ordered(items, 4, |n| {
+            thread::sleep(Duration::from_millis(20 - n));
+            n * n
+        });
+
+        assert_eq!(results, (0..20).map(|n| n * n).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_map_ordered_uses_threads() {
+        let threads: HashSet<_> = map_ordered(vec![(); 8], 4, |_| {
+            thread::sleep(Duration::from_millis(20));
+            thread::current().id()
+        }).into_iter().collect();
+
+        assert!(threads.len() > 1);
+    }
+
+    #[test]
+    fn test_map_ordered_edge_cases() {
+        assert_eq!(map_ordered(Vec::<i32>::new(), 4, |n| n), Vec::<i32>::new());
+        assert_eq!(map_ordered(vec![1, 2, 3], 0, |n| n + 1), vec![2, 3, 4]);
+        assert_eq!(map_ordered(vec![1, 2, 3], 100, |n| n + 1), vec![2, 3, 4]);
+    }
+}
@@ -0,0 +1,37 @@
+use common::Puzzle;
+
+include!(concat!(env!("OUT_DIR"), "/puzzles.rs"));
+
+pub fn find(year: i32, day: usize) -> Option<&'static Puzzle> {
+    PUZZLES.iter().find(|puzzle| puzzle.year == year && puzzle.day == day)
+}
+
+pub fn years() -> Vec<i32> {
+    let mut years: Vec<_> = PUZZLES.iter().map(|puzzle| puzzle.year).collect();
+    years.sort();
+    years.dedup();
+
+    years
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::registry::*;
+
+    #[test]
+    fn test_registered_days() {
+        assert!(years().contains(&2023));
+        assert!((1..=25).all(|day| find(2023, day).is_some_and(|puzzle| !puzzle.title.is_empty())));
+        assert!(find(2023, 26).is_none());
+        assert!(find(1999, 1).is_none());
+    }
+
+    #[test]
+    fn test_unique_days() {
+        let days: HashSet<_> = PUZZLES.iter().map(|puzzle| (puzzle.year, puzzle.day)).collect();
+
+        assert_eq!(days.len(), PUZZLES.len());
+    }
+}
@@ -0,0 +1,298 @@
+use std::time::Duration;
+
+use common::{Answer, Part, Puzzle, Run};
+use serde::{Deserialize, Serialize};
+
+use crate::answers::{AnswerFile, Status};
+use crate::bench::Stats;
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Record {
+    pub day: usize,
+    pub part: u8,
+    pub answer: Option<String>,
+    pub parse_ns: u128,
+    pub time_ns: u128,
+    pub status: String
+}
+
+pub fn records(results: &[(usize, Run)], expected: Option<&AnswerFile>) -> Vec<Record> {
+    let mut records = Vec::new();
+
+    for (day, run) in results {
+        for (part, number) in [(Part::One, 1), (Part::Two, 2)] {
+            let (Some(answer), Some(time)) = (run.answers.get(part), run.timings.get(part)) else { continue };
+            let status = match expected {
+                Some(expected) => expected.verify(*day, part, answer).to_string().to_lowercase(),
+                None if answer == &Answer::Unsolved => "unsolved".to_owned(),
+                None => "solved".to_owned()
+            };
+
+            records.push(Record {
+                day: *day,
+                part: number,
+                answer: (answer != &Answer::Unsolved).then(|| answer.to_string()),
+                parse_ns: run.timings.parse.as_nanos(),
+                time_ns: time.as_nanos(),
+                status
+            });
+        }
+    }
+
+    records
+}
+
+pub fn json(records: &[Record]) -> String {
+    serde_json::to_string_pretty(records).expect("Couldn't serialize results") + "\n"
+}
+
+pub fn csv(records: &[Record]) -> String {
+    let rows = records.iter().map(|r| format!(
+        "{},{},{},{},{},{}\n",
+        r.day, r.part, csv_field(r.answer.as_deref().unwrap_or("")), r.parse_ns, r.time_ns, r.status
+    ));
+
+    "day,part,answer,parse_ns,time_ns,status\n".to_owned() + &rows.collect::<String>()
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_owned()
+    }
+}
+
+pub fn summary_table(results: &[(usize, Run)]) -> String {
+    let rows: Vec<Vec<String>> = results.iter()
+        .map(|(day, run)| vec![
+            day.to_string(),
+            answer_cell(run.answers.part1.as_ref()),
+            answer_cell(run.answers.part2.as_ref()),
+            duration_cell(Some(run.timings.parse)),
+            duration_cell(run.timings.part1),
+            duration_cell(run.timings.part2)
+        ])
+        .collect();
+    let total: Duration = results.iter().map(|(_, run)| run.timings.total()).sum();
+
+    table(&["Day", "Part 1", "Part 2", "Parse", "Part 1 time", "Part 2 time"], &rows) + &format!("Total time: {}\n", format_duration(total))
+}
+
+pub fn bench_table(results: &[(usize, Vec<(&str, Stats)>)]) -> String {
+    let rows: Vec<Vec<String>> = results.iter()
+        .flat_map(|(day, stages)| stages.iter().map(move |(stage, stats)| vec![
+            day.to_string(),
+            stage.to_string(),
+            format_duration(stats.min),
+            format_duration(stats.median),
+            format_duration(stats.mean)
+        ]))
+        .collect();
+
+    table(&["Day", "Stage", "Min", "Median", "Mean"], &rows)
+}
+
+pub fn verify_table(results: &[(usize, Run)], expected: &AnswerFile) -> (String, Vec<Status>) {
+    let mut rows = Vec::new();
+    let mut statuses = Vec::new();
+
+    for (day, run) in results {
+        for (part, name) in [(Part::One, "1"), (Part::Two, "2")] {
+            let Some(actual) = run.answers.get(part) else { continue };
+            let status = expected.verify(*day, part, actual);
+
+            if status == Status::Missing && actual == &Answer::Unsolved {
+                continue;
+            }
+
+            rows.push(vec![
+                day.to_string(),
+                name.to_owned(),
+                expected.get(*day, part).unwrap_or("-").to_owned(),
+                actual.to_string(),
+                status.to_string()
+            ]);
+            statuses.push(status);
+        }
+    }
+
+    (table(&["Day", "Part", "Expected", "Actual", "Status"], &rows), statuses)
+}
+
+pub fn puzzle_table(days: &[(usize, Option<&Puzzle>)]) -> String {
+    let rows: Vec<Vec<String>> = days.iter()
+        .map(|(day, puzzle)| vec![day.to_string(), puzzle.map_or("not implemented", |p| p.title).to_owned()])
+        .collect();
+
+    table(&["Day", "Title"], &rows)
+}
+
+pub fn timings_line(run: &Run) -> String {
+    let mut parts = vec![format!("parse: {}", format_duration(run.timings.parse))];
+    parts.extend(run.timings.part1.map(|d| format!("part 1: {}", format_duration(d))));
+    parts.extend(run.timings.part2.map(|d| format!("part 2: {}", format_duration(d))));
+
+    parts.join(", ")
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    format!("{:.2?}", duration)
+}
+
+fn answer_cell(answer: Option<&Answer>) -> String {
+    answer.map(Answer::to_string).unwrap_or("-".to_owned())
+}
+
+fn duration_cell(duration: Option<Duration>) -> String {
+    duration.map(format_duration).unwrap_or("-".to_owned())
+}
+
+fn table(header: &[&str], rows: &[Vec<String>]) -> String {
+    let header: Vec<String> = header.iter().map(|s| s.to_string()).collect();
+    let widths: Vec<usize> = (0..header.len())
+        .map(|i| rows.iter().chain([&header]).map(|r| r[i].chars().count()).max().unwrap())
+        .collect();
+
+    let format_row = |row: &Vec<String>| row.iter().zip(&widths).enumerate()
+        .map(|(i, (cell, &w))| if i == 0 { format!("{cell:>w$}") } else { format!("{cell:<w$}") })
+        .collect::<Vec<_>>()
+        .join(" | ")
+        .trim_end()
+        .to_owned() + "\n";
+    let separator = widths.iter().map(|w| "-".repeat(*w)).collect::<Vec<_>>().join("-+-") + "\n";
+
+    format_row(&header) + &separator + &rows.iter().map(format_row).collect::<String>()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use common::{Answer, Answers, Puzzle, Run, Timings};
+
+    use crate::report::*;
+
+    #[test]
+    fn test_summary_table() {
+        let results = vec![
+            (1, Run {
+                answers: Answers { part1: Some(Answer::Integer(142)), part2: Some(Answer::Integer(281)) },
+                timings: Timings { parse: Duration::from_micros(20), part1: Some(Duration::from_micros(1500)), part2: Some(Duration::from_millis(3)) }
+            }),
+            (25, Run {
+                answers: Answers { part1: None, part2: Some(Answer::Unsolved) },
+                timings: Timings { parse: Duration::from_micros(30), part1: None, part2: Some(Duration::from_nanos(50)) }
+            })
+        ];
+
+        assert_eq!(summary_table(&results), "Day | Part 1 | Part 2   | Parse   | Part 1 time | Part 2 time
+----+--------+----------+---------+-------------+------------
+  1 | 142    | 281      | 20.00µs | 1.50ms      | 3.00ms
+ 25 | -      | unsolved | 30.00µs | -           | 50.00ns
+Total time: 4.55ms
+");
+    }
+
+    #[test]
+    fn test_bench_table() {
+        let stats = Stats { min: Duration::from_millis(1), median: Duration::from_millis(2), mean: Duration::from_millis(4) };
+        let results = vec![(7, vec![("parse", stats), ("total", stats)])];
+
+        assert_eq!(bench_table(&results), "Day | Stage | Min    | Median | Mean
+----+-------+--------+--------+-------
+  7 | parse | 1.00ms | 2.00ms | 4.00ms
+  7 | total | 1.00ms | 2.00ms | 4.00ms
+");
+    }
+
+    #[test]
+    fn test_puzzle_table() {
+        let puzzle = Puzzle { year: 2023, day: 1, title: "Trebuchet?!", solve: |_, _| unreachable!(), visualize: |_, _, _| unreachable!() };
+
+        assert_eq!(puzzle_table(&[(1, Some(&puzzle)), (2, None)]), "Day | Title
+----+----------------
+  1 | Trebuchet?!
+  2 | not implemented
+");
+    }
+
+    #[test]
+    fn test_verify_table() {
+        let expected = AnswerFile::parse("[day_01]\npart1 = \"142\"\npart2 = \"281\"\n").unwrap();
+        let results = vec![
+            (1, Run {
+                answers: Answers { part1: Some(Answer::Integer(142)), part2: Some(Answer::Integer(280)) },
+                timings: Default::default()
+            }),
+            (2, Run {
+                answers: Answers { part1: Some(Answer::Integer(8)), part2: Some(Answer::Unsolved) },
+                timings: Default::default()
+            })
+        ];
+
+        let (table, statuses) = verify_table(&results, &expected);
+
+        assert_eq!(table, "Day | Part | Expected | Actual | Status
+----+------+----------+--------+--------
+  1 | 1    | 142      | 142    | pass
+  1 | 2    | 281      | 280    | FAIL
+  2 | 1    | -        | 8      | missing
+");
+        assert_eq!(statuses, vec![Status::Pass, Status::Fail, Status::Missing]);
+    }
+
+    fn example_results() -> Vec<(usize, Run)> {
+        vec![
+            (1, Run {
+                answers: Answers { part1: Some(Answer::Integer(142)), part2: Some(Answer::Text("a,\"b\"".to_owned())) },
+                timings: Timings { parse: Duration::from_nanos(20), part1: Some(Duration::from_nanos(1500)), part2: Some(Duration::from_nanos(3)) }
+            }),
+            (25, Run {
+                answers: Answers { part1: None, part2: Some(Answer::Unsolved) },
+                timings: Timings { parse: Duration::from_nanos(30), part1: None, part2: Some(Duration::from_nanos(50)) }
+            })
+        ]
+    }
+
+    #[test]
+    fn test_records() {
+        let expected = AnswerFile::parse("[day_01]\npart1 = \"141\"\n").unwrap();
+
+        let statuses: Vec<String> = records(&example_results(), None).into_iter().map(|r| r.status).collect();
+        assert_eq!(statuses, vec!["solved", "solved", "unsolved"]);
+
+        let statuses: Vec<String> = records(&example_results(), Some(&expected)).into_iter().map(|r| r.status).collect();
+        assert_eq!(statuses, vec!["fail", "missing", "missing"]);
+    }
+
+    #[test]
+    fn test_json() {
+        let records = records(&example_results(), None);
+        let value: serde_json::Value = serde_json::from_str(&json(&records)).unwrap();
+
+        assert_eq!(value[0], serde_json::json!({ "day": 1, "part": 1, "answer": "142", "parse_ns": 20, "time_ns": 1500, "status": "solved" }));
+        assert_eq!(value[2], serde_json::json!({ "day": 25, "part": 2, "answer": null, "parse_ns": 30, "time_ns": 50, "status": "unsolved" }));
+    }
+
+    #[test]
+    fn test_csv() {
+        let records = records(&example_results(), None);
+
+        assert_eq!(csv(&records), "day,part,answer,parse_ns,time_ns,status
+1,1,142,20,1500,solved
+1,2,\"a,\"\"b\"\"\",20,3,solved
+25,2,,30,50,unsolved
+");
+    }
+
+    #[test]
+    fn test_timings_line() {
+        let run = Run {
+            answers: Answers { part1: None, part2: Some(Answer::Integer(1)) },
+            timings: Timings { parse: Duration::from_micros(5), part1: None, part2: Some(Duration::from_millis(12)) }
+        };
+
+        assert_eq!(timings_line(&run), "parse: 5.00µs, part 2: 12.00ms");
+    }
+}
@@ -0,0 +1,259 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::SESSION_PLACEHOLDER;
+
+const GITIGNORE_ENTRIES: [&str; 3] = ["/input", "/target", "/.cargo/config.toml"];
+
+pub fn init(root: &Path, year: i32) -> io::Result<Vec<String>> {
+    let mut actions = Vec::new();
+
+    if !root.join("input").exists() {
+        fs::create_dir_all(root.join("input"))?;
+        actions.push("Created input/".to_owned());
+    }
+
+    let config = root.join(".cargo").join("config.toml");
+
+    if !config.exists() {
+        fs::create_dir_all(root.join(".cargo"))?;
+        fs::write(&config, format!("[env]\nYEAR = \"{year}\"\nSESSION = \"{SESSION_PLACEHOLDER}\"\n"))?;
+        actions.push("Created .cargo/config.toml, put your session cookie in it".to_owned());
+    }
+
+    let gitignore = fs::read_to_string(root.join(".gitignore")).unwrap_or_default();
+    let missing: Vec<_> = GITIGNORE_ENTRIES.iter()
+        .filter(|&&entry| gitignore.lines().all(|line| line.trim() != entry))
+        .collect();
+
+    if !missing.is_empty() {
+        let mut gitignore = gitignore;
+
+        if !gitignore.is_empty() && !gitignore.ends_with('\n') {
+            gitignore.push('\n');
+        }
+
+        missing.iter().for_each(|entry| gitignore.push_str(&format!("{entry}\n")));
+        fs::write(root.join(".gitignore"), gitignore)?;
+        actions.push("Updated .gitignore".to_owned());
+    }
+
+    for day in 1..=25 {
+        actions.extend(new_day(root, year, day)?);
+    }
+
+    Ok(actions)
+}
+
+pub fn new_day(root: &Path, year: i32, day: usize) -> io::Result<Vec<String>> {
+    let mut actions = Vec::new();
+    let dir = day_dir(year, day);
+    let crate_dir = root.join(&dir);
+
+    if !crate_dir.join("Cargo.toml").exists() {
+        fs::create_dir_all(crate_dir.join("src"))?;
+        fs::write(crate_dir.join("Cargo.toml"), manifest_template(year, day))?;
+        fs::write(crate_dir.join("src").join("lib.rs"), lib_template(year, day))?;
+        actions.push(format!("Created {dir}"));
+    }
+
+    if update(&root.join("Cargo.toml"), |s| add_member(s, &dir))? {
+        actions.push(format!("Added {dir} to the workspace"));
+    }
+
+    if update(&root.join("runner").join("Cargo.toml"), |s| add_dependency(s, year, day))? {
+        actions.push(format!("Added {} to runner/Cargo.toml", crate_name(year, day)));
+    }
+
+    Ok(actions)
+}
+
+fn update<F: Fn(&str) -> Option<String>>(path: &Path, f: F) -> io::Result<bool> {
+    match f(&fs::read_to_string(path)?) {
+        Some(updated) => fs::write(path, updated).map(|_| true),
+        None => Ok(false)
+    }
+}
+
+pub(crate) fn day_dir(year: i32, day: usize) -> String {
+    format!("year_{year}/day_{:0>2}", day)
+}
+
+pub(crate) fn crate_name(year: i32, day: usize) -> String {
+    format!("year_{year}_day_{:0>2}", day)
+}
+
+fn manifest_template(year: i32, day: usize) -> String {
+    format!("[package]
+name = \"{}\"
+version = \"0.1.0\"
+edition = \"2021\"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+common = {{ path = \"../../common\" }}
+", crate_name(year, day))
+}
+
+fn lib_template(year: i32, day: usize) -> String {
+    let name = format!("Day{:0>2}", day);
+
+    format!("use common::{{Answer, Solution}};
+
+common::register!({name}, year: {year}, day: {day}, title: \"Day {day}\");
+
+pub struct {name};
+
+impl Solution for {name} {{
+    fn parse(_input: &str) -> Self {{
+        {name}
+    }}
+
+    fn part1(&self) -> Answer {{
+        Answer::Unsolved
+    }}
+}}
+
+#[cfg(test)]
+mod tests {{
+    use crate::*;
+
+    #[test]
+    fn example_part1() {{
+        assert_eq!({name}::parse(EXAMPLE_INPUT).part1(), Answer::Unsolved);
+    }}
+
+    const EXAMPLE_INPUT: &str = \"\";
+}}
+")
+}
+
+fn add_member(manifest: &str, dir: &str) -> Option<String> {
+    insert_sorted(manifest, "members = [", |line| line.starts_with(']'), &format!("    \"{dir}\","),
+        |line| line.trim().strip_prefix("\"year_").map(str::to_owned))
+}
+
+fn add_dependency(manifest: &str, year: i32, day: usize) -> Option<String> {
+    let line = format!("{} = {{ path = \"../{}\" }}", crate_name(year, day), day_dir(year, day));
+
+    insert_sorted(manifest, "[dependencies]", |line| line.starts_with('['), &line,
+        |line| line.split_once(" = ").map(|(name, _)| name.to_owned()))
+}
+
+// inserts `line` into the block following the `start` line, before the first line with a greater key
+fn insert_sorted<E, K>(text: &str, start: &str, is_end: E, line: &str, key: K) -> Option<String>
+where
+    E: Fn(&str) -> bool,
+    K: Fn(&str) -> Option<String>
+{
+    let mut lines: Vec<&str> = text.lines().collect();
+    let first = lines.iter().position(|l| l.trim_start().starts_with(start))? + 1;
+    let mut last = lines[first..].iter().position(|l| is_end(l)).map_or(lines.len(), |i| first + i);
+
+    while last > first && lines[last - 1].trim().is_empty() {
+        last -= 1;
+    }
+
+    if lines[first..last].iter().any(|l| l.trim() == line.trim()) {
+        return None;
+    }
+
+    let new_key = key(line);
+    let at = (first..last)
+        .find(|&i| key(lines[i]).is_some_and(|k| Some(k) > new_key))
+        .unwrap_or(last);
+
+    lines.insert(at, line);
+
+    Some(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, process};
+
+    use crate::scaffold::*;
+
+    #[test]
+    fn test_add_member() {
+        let updated = add_member(WORKSPACE, "year_2023/day_02").unwrap();
+
+        assert!(updated.contains("    \"year_2023/day_01\",\n    \"year_2023/day_02\",\n    \"year_2023/day_03\",\n]"));
+        assert!(add_member(&updated, "year_2023/day_02").is_none());
+        assert!(add_member(WORKSPACE, "year_2024/day_01").unwrap().contains("    \"year_2023/day_03\",\n    \"year_2024/day_01\",\n]"));
+    }
+
+    #[test]
+    fn test_add_dependency() {
+        let updated = add_dependency(RUNNER_MANIFEST, 2023, 2).unwrap();
+
+        assert!(updated.ends_with("year_2023_day_01 = { path = \"../year_2023/day_01\" }
+year_2023_day_02 = { path = \"../year_2023/day_02\" }
+year_2023_day_03 = { path = \"../year_2023/day_03\" }
+"));
+        assert!(add_dependency(&updated, 2023, 2).is_none());
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = env::temp_dir().join(format!("runner-scaffold-{}", process::id()));
+        fs::create_dir_all(root.join("runner")).unwrap();
+        fs::write(root.join("Cargo.toml"), WORKSPACE).unwrap();
+        fs::write(root.join("runner").join("Cargo.toml"), RUNNER_MANIFEST).unwrap();
+
+        assert_eq!(new_day(&root, 2024, 5).unwrap().len(), 3);
+        assert!(new_day(&root, 2024, 5).unwrap().is_empty());
+
+        let lib = fs::read_to_string(root.join("year_2024/day_05/src/lib.rs")).unwrap();
+        assert!(lib.contains("pub struct Day05;"));
+        assert!(lib.contains("common::register!(Day05, year: 2024, day: 5, title: \"Day 5\");"));
+        assert!(lib.contains("const EXAMPLE_INPUT: &str"));
+        assert!(fs::read_to_string(root.join("year_2024/day_05/Cargo.toml")).unwrap().contains("name = \"year_2024_day_05\""));
+
+        fs::write(root.join("year_2024/day_05/src/lib.rs"), "// solved").unwrap();
+        new_day(&root, 2024, 5).unwrap();
+        assert_eq!(fs::read_to_string(root.join("year_2024/day_05/src/lib.rs")).unwrap(), "// solved");
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_init() {
+        let root = env::temp_dir().join(format!("runner-init-{}", process::id()));
+        fs::create_dir_all(root.join("runner")).unwrap();
+        fs::write(root.join("Cargo.toml"), WORKSPACE).unwrap();
+        fs::write(root.join("runner").join("Cargo.toml"), RUNNER_MANIFEST).unwrap();
+        fs::write(root.join(".gitignore"), "/target").unwrap();
+
+        assert!(!init(&root, 2024).unwrap().is_empty());
+        assert!(init(&root, 2024).unwrap().is_empty());
+
+        assert!(fs::read_to_string(root.join(".cargo/config.toml")).unwrap().contains("YEAR = \"2024\""));
+        assert_eq!(fs::read_to_string(root.join(".gitignore")).unwrap(), "/target\n/input\n/.cargo/config.toml\n");
+        assert_eq!(fs::read_to_string(root.join("runner/Cargo.toml")).unwrap().matches("year_2024_day_").count(), 25);
+        assert!(root.join("year_2024/day_25/src/lib.rs").exists());
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    const WORKSPACE: &str = "[workspace]
+
+members = [
+    \"runner\",
+    \"common\",
+    \"year_2023/day_01\",
+    \"year_2023/day_03\",
+]
+";
+
+    const RUNNER_MANIFEST: &str = "[package]
+name = \"runner\"
+
+[dependencies]
+chrono = \"0.4.31\"
+year_2023_day_01 = { path = \"../year_2023/day_01\" }
+year_2023_day_03 = { path = \"../year_2023/day_03\" }
+";
+}
@@ -0,0 +1,70 @@
+use crate::answers::Hint;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Verdict {
+    Correct,
+    Incorrect(Option<Hint>),
+    RateLimited(Option<String>),
+    AlreadySolved
+}
+
+pub fn parse_verdict(html: &str) -> Option<Verdict> {
+    if html.contains("That's the right answer") {
+        Some(Verdict::Correct)
+    } else if html.contains("That's not the right answer") {
+        let hint = if html.contains("your answer is too high") {
+            Some(Hint::TooHigh)
+        } else if html.contains("your answer is too low") {
+            Some(Hint::TooLow)
+        } else {
+            None
+        };
+
+        Some(Verdict::Incorrect(hint))
+    } else if html.contains("You gave an answer too recently") {
+        let wait = html.to_lowercase().split_once(" left to wait")
+            .and_then(|(before, _)| before.rsplit_once("you have "))
+            .map(|(_, wait)| wait.to_owned());
+
+        Some(Verdict::RateLimited(wait))
+    } else if html.contains("You don't seem to be solving the right level") {
+        Some(Verdict::AlreadySolved)
+    } else {
+        None
+    }
+}
+
+impl std::fmt::Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::Incorrect(Some(hint)) => write!(f, "incorrect, {hint}"),
+            Verdict::Incorrect(None) => write!(f, "incorrect"),
+            Verdict::RateLimited(Some(wait)) => write!(f, "rate limited, {wait} left to wait"),
+            Verdict::RateLimited(None) => write!(f, "rate limited"),
+            Verdict::AlreadySolved => write!(f, "this part is already solved or not unlocked yet")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answers::Hint;
+    use crate::submit::*;
+
+    fn article(text: &str) -> String {
+        format!("<html><body><main>\n<article><p>{text}</p></article>\n</main></body></html>")
+    }
+
+    #[test]
+    fn test_parse_verdict() {
+        assert_eq!(parse_verdict(&article("That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer.")), Some(Verdict::Correct));
+        assert_eq!(parse_verdict(&article("That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data.")), Some(Verdict::Incorrect(Some(Hint::TooHigh))));
+        assert_eq!(parse_verdict(&article("That's not the right answer; your answer is too low.")), Some(Verdict::Incorrect(Some(Hint::TooLow))));
+        assert_eq!(parse_verdict(&article("That's not the right answer.  If you're stuck, make sure you're using the full input data.")), Some(Verdict::Incorrect(None)));
+        assert_eq!(parse_verdict(&article("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 23s left to wait.")), Some(Verdict::RateLimited(Some("4m 23s".to_owned()))));
+        assert_eq!(parse_verdict(&article("You gave an answer too recently.")), Some(Verdict::RateLimited(None)));
+        assert_eq!(parse_verdict(&article("You don't seem to be solving the right level.  Did you already complete it?")), Some(Verdict::AlreadySolved));
+        assert_eq!(parse_verdict(&article("Something else")), None);
+    }
+}
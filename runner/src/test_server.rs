@@ -0,0 +1,48 @@
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::thread::{self, JoinHandle};
+
+pub fn serve(status: &str, body: &str) -> (String, JoinHandle<String>) {
+    let (url, handle) = serve_sequence(&[(status, body)]);
+
+    (url, thread::spawn(move || handle.join().unwrap().remove(0)))
+}
+
+pub fn serve_sequence(responses: &[(&str, &str)]) -> (String, JoinHandle<Vec<String>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let responses: Vec<_> = responses.iter()
+        .map(|(status, body)| format!("HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}", body.len()))
+        .collect();
+
+    let handle = thread::spawn(move || {
+        responses.iter().map(|response| {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = Vec::new();
+            let mut buf = [0; 1024];
+
+            while !request.windows(4).any(|w| w == b"\r\n\r\n") || request.len() < expected_length(&request) {
+                let n = stream.read(&mut buf).unwrap();
+                if n == 0 {
+                    break;
+                }
+                request.extend_from_slice(&buf[..n]);
+            }
+
+            stream.write_all(response.as_bytes()).unwrap();
+            String::from_utf8(request).unwrap()
+        }).collect()
+    });
+
+    (url, handle)
+}
+
+fn expected_length(request: &[u8]) -> usize {
+    let request = String::from_utf8_lossy(request).to_lowercase();
+    let Some((head, _)) = request.split_once("\r\n\r\n") else { return 0 };
+    let body_length = head.lines()
+        .find_map(|line| line.strip_prefix("content-length: "))
+        .map_or(0, |n| n.trim().parse().unwrap());
+
+    head.len() + 4 + body_length
+}
@@ -0,0 +1,158 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use common::Part;
+
+use crate::report::{format_duration, Record};
+use crate::scaffold::{crate_name, day_dir};
+use crate::{example_path, input_path};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+pub type Snapshot = BTreeMap<PathBuf, SystemTime>;
+
+pub fn watch(year: i32, day: usize, part: Option<Part>, example: Option<usize>) {
+    let paths = watched_paths(year, day, example);
+    let mut last_snapshot = None;
+    let mut previous = None;
+
+    println!("Watching {}", paths.iter().map(|p| p.display().to_string()).collect::<Vec<_>>().join(", "));
+
+    loop {
+        let snapshot = snapshot(&paths);
+
+        if last_snapshot.as_ref() != Some(&snapshot) {
+            last_snapshot = Some(snapshot);
+            previous = run(year, day, part, example, previous);
+        }
+
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+pub fn watched_paths(year: i32, day: usize, example: Option<usize>) -> Vec<PathBuf> {
+    let input = match example {
+        Some(number) => example_path(year, day, number),
+        None => input_path(year, day)
+    };
+
+    vec![Path::new(&day_dir(year, day)).join("src"), PathBuf::from(input)]
+}
+
+pub fn snapshot(paths: &[PathBuf]) -> Snapshot {
+    let mut snapshot = Snapshot::new();
+
+    for path in paths {
+        collect_modified(path, &mut snapshot);
+    }
+
+    snapshot
+}
+
+fn collect_modified(path: &Path, snapshot: &mut Snapshot) {
+    let Ok(metadata) = fs::metadata(path) else { return };
+
+    if metadata.is_dir() {
+        for entry in fs::read_dir(path).into_iter().flatten().flatten() {
+            collect_modified(&entry.path(), snapshot);
+        }
+    } else if let Ok(modified) = metadata.modified() {
+        snapshot.insert(path.to_owned(), modified);
+    }
+}
+
+fn run(year: i32, day: usize, part: Option<Part>, example: Option<usize>, previous: Option<Vec<Record>>) -> Option<Vec<Record>> {
+    let mut args = vec!["run".to_owned(), "-q".to_owned(), "-p".to_owned(), "runner".to_owned(), "--".to_owned(),
+        day.to_string(), "--year".to_owned(), year.to_string(), "--format".to_owned(), "json".to_owned()];
+    args.extend(part.map(|part| ["--part".to_owned(), if part == Part::One { "1" } else { "2" }.to_owned()]).into_iter().flatten());
+    args.extend(example.map(|number| ["--example".to_owned(), number.to_string()]).into_iter().flatten());
+
+    println!("\n== Day {day} of {year} ==");
+
+    let output = Command::new("cargo").args(&args).stderr(Stdio::inherit()).output();
+    let records = output.ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| serde_json::from_slice::<Vec<Record>>(&output.stdout).ok());
+
+    match &records {
+        Some(records) => answer_diff(previous.as_deref(), records).iter().for_each(|line| println!("{line}")),
+        None => println!("Solving failed")
+    }
+
+    let tests = Command::new("cargo").args(["test", "-q", "-p", &crate_name(year, day)]).stdout(Stdio::null()).status();
+
+    match tests {
+        Ok(status) if status.success() => println!("Tests passed"),
+        _ => println!("Tests failed")
+    }
+
+    records.or(previous)
+}
+
+pub fn answer_diff(previous: Option<&[Record]>, current: &[Record]) -> Vec<String> {
+    current.iter()
+        .map(|record| {
+            let answer = record.answer.as_deref().unwrap_or("unsolved");
+            let time = format_duration(Duration::from_nanos(record.time_ns as u64));
+            let before = previous.and_then(|p| p.iter().find(|r| r.part == record.part));
+
+            match before.map(|r| r.answer.as_deref().unwrap_or("unsolved")) {
+                None => format!("Part {}: {answer} ({time})", record.part),
+                Some(old) if old == answer => format!("Part {}: {answer}, unchanged ({time})", record.part),
+                Some(old) => format!("Part {}: {old} -> {answer} ({time})", record.part)
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, process};
+
+    use crate::report::Record;
+    use crate::watch::*;
+
+    fn record(part: u8, answer: Option<&str>) -> Record {
+        Record { day: 5, part, answer: answer.map(str::to_owned), parse_ns: 0, time_ns: 2_000_000, status: "solved".to_owned() }
+    }
+
+    #[test]
+    fn test_answer_diff() {
+        let previous = [record(1, Some("35")), record(2, None)];
+        let current = [record(1, Some("35")), record(2, Some("46"))];
+
+        assert_eq!(answer_diff(None, &current), vec!["Part 1: 35 (2.00ms)", "Part 2: 46 (2.00ms)"]);
+        assert_eq!(answer_diff(Some(&previous), &current), vec!["Part 1: 35, unchanged (2.00ms)", "Part 2: unsolved -> 46 (2.00ms)"]);
+    }
+
+    #[test]
+    fn test_watched_paths() {
+        assert_eq!(watched_paths(2023, 5, None), vec![PathBuf::from("year_2023/day_05/src"), PathBuf::from("input/2023/day_05.txt")]);
+        assert_eq!(watched_paths(2023, 5, Some(2))[1], PathBuf::from("input/2023/day_05.example.2.txt"));
+    }
+
+    #[test]
+    fn test_snapshot() {
+        let dir = env::temp_dir().join(format!("runner-watch-{}", process::id()));
+        fs::create_dir_all(dir.join("src").join("nested")).unwrap();
+        fs::write(dir.join("src").join("lib.rs"), "").unwrap();
+        let paths = [dir.join("src"), dir.join("input.txt")];
+
+        let before = snapshot(&paths);
+        assert_eq!(before.len(), 1);
+
+        fs::write(dir.join("src").join("nested").join("grid.rs"), "").unwrap();
+        fs::write(dir.join("input.txt"), "1 2 3").unwrap();
+        let after = snapshot(&paths);
+
+        assert_eq!(after.len(), 3);
+        assert_ne!(before, after);
+        assert_eq!(after, snapshot(&paths));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,36 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+#[test]
+fn test_visualize_from_stdin() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_runner"))
+        .args(["17", "--year", "2023", "--input", "-", "--visualize", "plain"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("Couldn't start the runner");
+
+    child.stdin.take().unwrap().write_all(DAY_17_EXAMPLE.as_bytes()).unwrap();
+    let output = child.wait_with_output().unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert!(stdout.starts_with("102\n94\n"));
+    assert!(stdout.contains("\nPart 1:\n2>>34^>>>1323\n"));
+    assert!(stdout.contains("\nPart 2:\n2>>>>>>>>1323\n"));
+}
+
+const DAY_17_EXAMPLE: &str = "2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533";
@@ -27,7 +27,6 @@ impl Node {
 }
 
 pub struct Match {
-    pub position: usize,
     pub word: String
 }
 
@@ -146,13 +145,12 @@ impl Iterator for Iter {
 
             if self.current_node.borrow().in_dict {
                 let word = self.current_node.borrow().word.clone().unwrap();
-                let start = i - (word.len() - 1);
                 self.position = i + 1;
-                return Some(Match { position: start, word });
+                return Some(Match { word });
             }
         }
 
         self.position = self.word.len();
-        return None;
+        None
     }
 }
\ No newline at end of file
@@ -3,22 +3,34 @@ mod aho_corasick;
 use std::collections::HashMap;
 
 use aho_corasick::AhoCorasick;
+use common::{Answer, Solution};
 
-pub fn solve(input: String) {
-    let lines: Vec<_> = input.lines().collect();
-    let part1 = lines
-        .clone()
-        .into_iter()
-        .map(task1)
-        .sum::<u64>();
-
-    let part2 = lines
-        .into_iter()
-        .map(task2)
-        .sum::<u64>();
-
-    println!("{part1}");
-    println!("{part2}");
+common::register!(Day01, year: 2023, day: 1, title: "Trebuchet?!");
+
+pub struct Day01 {
+    lines: Vec<String>
+}
+
+impl Solution for Day01 {
+    fn parse(input: &str) -> Self {
+        Day01 { lines: input.lines().map(str::to_owned).collect() }
+    }
+
+    fn part1(&self) -> Answer {
+        self.lines
+            .iter()
+            .map(|l| task1(l))
+            .sum::<u64>()
+            .into()
+    }
+
+    fn part2(&self) -> Answer {
+        self.lines
+            .iter()
+            .map(|l| task2(l))
+            .sum::<u64>()
+            .into()
+    }
 }
 
 fn task1(line: &str) -> u64 {
@@ -36,7 +48,7 @@ fn task2(line: &str) -> u64 {
 
     let ac = AhoCorasick::from_words(words.clone());
 
-    let digits: Vec<_> = ac.find_matches(&line).map(|m| *mapper.get(&m.word).unwrap()).collect();
+    let digits: Vec<_> = ac.find_matches(line).map(|m| *mapper.get(&m.word).unwrap()).collect();
 
     digits[0] * 10 + digits.last().unwrap()
 }
\ No newline at end of file
@@ -1,14 +1,30 @@
 use anyhow::Error;
+use common::{Answer, Solution};
 use std::str::FromStr;
 
-pub fn solve(input: String) {
-    let games = match input.lines().map(|s| s.parse::<Game>()).collect::<Result<Vec<_>, _>>() {
-        Ok(v) => v,
-        Err(e) => panic!("Couldn't parse input file: {:?}", e)
-    };
+common::register!(Day02, year: 2023, day: 2, title: "Cube Conundrum");
 
-    println!("{}", games.iter().filter_map(part1).sum::<usize>());
-    println!("{}", games.iter().map(part2).sum::<usize>());
+pub struct Day02 {
+    games: Vec<Game>
+}
+
+impl Solution for Day02 {
+    fn parse(input: &str) -> Self {
+        let games = match input.lines().map(|s| s.parse::<Game>()).collect::<Result<Vec<_>, _>>() {
+            Ok(v) => v,
+            Err(e) => panic!("Couldn't parse input file: {:?}", e)
+        };
+
+        Day02 { games }
+    }
+
+    fn part1(&self) -> Answer {
+        self.games.iter().filter_map(part1).sum::<usize>().into()
+    }
+
+    fn part2(&self) -> Answer {
+        self.games.iter().map(part2).sum::<usize>().into()
+    }
 }
 
 fn part1(game: &Game) -> Option<usize> {
@@ -1,31 +1,47 @@
 use std::collections::HashMap;
 
-pub fn solve(input: String) {
-    let map: Vec<Vec<char>> = input.lines().map(|l| l.chars().collect()).collect();
-    
+use common::geometry::Point;
+use common::grid::Grid;
+use common::{Answer, Solution};
 
-    println!("{}", part1(&map));
-    println!("{}", part2(&map));
+common::register!(Day03, year: 2023, day: 3, title: "Gear Ratios");
+
+pub struct Day03 {
+    map: Grid<char>
 }
 
-fn part1(map: &Vec<Vec<char>>) -> usize {
+impl Solution for Day03 {
+    fn parse(input: &str) -> Self {
+        Day03 { map: input.parse().expect("Couldn't parse input") }
+    }
+
+    fn part1(&self) -> Answer {
+        part1(&self.map).into()
+    }
+
+    fn part2(&self) -> Answer {
+        part2(&self.map).into()
+    }
+}
+
+fn part1(map: &Grid<char>) -> usize {
     let mut result: usize = 0;
 
-    for (y, row) in map.iter().enumerate() {
+    for (y, row) in map.rows().enumerate() {
         let mut current_number = 0;
         let mut is_part_number = false;
 
         for (x, c) in row.iter().enumerate() {
-            if c.is_digit(10) {
+            if c.is_ascii_digit() {
                 current_number *= 10;
                 current_number += *c as usize - '0' as usize;
 
                 if !is_part_number {
-                    is_part_number = has_symbol_left_to(&map, (x, y));
+                    is_part_number = has_symbol_left_to(map, (x, y));
                 }
             } else {
                 if !is_part_number {
-                    is_part_number = has_symbol_left_to(&map, (x, y)) || has_symbol_left_to(&map, (x + 1, y));
+                    is_part_number = has_symbol_left_to(map, (x, y)) || has_symbol_left_to(map, (x + 1, y));
                 }
 
                 if current_number != 0 && is_part_number {
@@ -45,27 +61,23 @@ fn part1(map: &Vec<Vec<char>>) -> usize {
     result
 }
 
-fn has_symbol_left_to(map: &Vec<Vec<char>>, (x, y): (usize, usize)) -> bool {
-    if x == 0 {
-        false
-    } else {
-        (y > 0 && is_symbol(map[y - 1][x - 1])) || is_symbol(map[y][x - 1]) || (y < map.len() - 1 && is_symbol(map[y + 1][x - 1]))
-    }
+fn has_symbol_left_to(map: &Grid<char>, (x, y): (usize, usize)) -> bool {
+    x != 0 && (y.saturating_sub(1)..=y + 1).any(|y| map.get(Point::new(x - 1, y)).is_some_and(|&c| is_symbol(c)))
 }
 
 fn is_symbol(c: char) -> bool {
-    !c.is_digit(10) && c != '.'
+    !c.is_ascii_digit() && c != '.'
 }
 
-fn part2(map: &Vec<Vec<char>>) -> usize {
-    let mut adjacent_numbers = HashMap::<(usize, usize), Vec<usize>>::new();
+fn part2(map: &Grid<char>) -> usize {
+    let mut adjacent_numbers = HashMap::<Point<usize>, Vec<usize>>::new();
 
-    for (y, row) in map.iter().enumerate() {
+    for (y, row) in map.rows().enumerate() {
         let mut current_number = 0;
-        let mut gears = Vec::<(usize, usize)>::new();
+        let mut gears = Vec::<Point<usize>>::new();
 
         for (x, c) in row.iter().enumerate() {
-            if c.is_digit(10) {
+            if c.is_ascii_digit() {
                 current_number *= 10;
                 current_number += *c as usize - '0' as usize;
                 gears.append(&mut gears_left_to(map, (x, y)));
@@ -93,22 +105,13 @@ fn part2(map: &Vec<Vec<char>>) -> usize {
     adjacent_numbers.values().filter(|v| v.len() == 2).map(|v| v[0] * v[1]).sum()
 }
 
-fn gears_left_to(map: &Vec<Vec<char>>, (x, y): (usize, usize)) -> Vec<(usize, usize)> {
-    let mut result = Vec::new();
-    
-    if x != 0 {
-        if y > 0 && map[y - 1][x - 1] == '*' {
-            result.push((x - 1, y - 1));
-        }
-
-        if map[y][x - 1] == '*' {
-            result.push((x - 1, y));
-        }
-
-        if y < map.len() - 1 && map[y + 1][x - 1] == '*' {
-            result.push((x - 1, y + 1));
-        }
+fn gears_left_to(map: &Grid<char>, (x, y): (usize, usize)) -> Vec<Point<usize>> {
+    if x == 0 {
+        return Vec::new();
     }
 
-    result
+    (y.saturating_sub(1)..=y + 1)
+        .map(|y| Point::new(x - 1, y))
+        .filter(|&point| map.get(point) == Some(&'*'))
+        .collect()
 }
\ No newline at end of file
@@ -1,26 +1,42 @@
 use anyhow::Error;
+use common::{Answer, Solution};
 use std::collections::HashSet;
-use std::iter::repeat;
+use std::iter::repeat_n;
 use std::str::FromStr;
 
-pub fn solve(input: String) {
-    let cards = input.lines()
-        .map(number_of_matches)
-        .collect::<Result<Vec<usize>, _>>()
-        .expect("Couldn't parse input file");
+common::register!(Day04, year: 2023, day: 4, title: "Scratchcards");
 
-    println!("{}", part1(&cards));
-    println!("{}", part2(&cards));
+pub struct Day04 {
+    cards: Vec<usize>
 }
 
-fn part1(values: &Vec<usize>) -> usize {
+impl Solution for Day04 {
+    fn parse(input: &str) -> Self {
+        let cards = input.lines()
+            .map(number_of_matches)
+            .collect::<Result<Vec<usize>, _>>()
+            .expect("Couldn't parse input file");
+
+        Day04 { cards }
+    }
+
+    fn part1(&self) -> Answer {
+        part1(&self.cards).into()
+    }
+
+    fn part2(&self) -> Answer {
+        part2(&self.cards).into()
+    }
+}
+
+fn part1(values: &[usize]) -> usize {
     values.iter()
         .map(|n| n.checked_sub(1).map(|x| 1 << x).unwrap_or(0))
         .sum()
 }
 
-fn part2(values: &Vec<usize>) -> usize {
-    let mut counts: Vec<usize> = repeat(1).take(values.len()).collect();
+fn part2(values: &[usize]) -> usize {
+    let mut counts: Vec<usize> = repeat_n(1, values.len()).collect();
     let mut result = 0;
 
     for (i, v) in values.iter().enumerate() {
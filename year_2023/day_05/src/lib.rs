@@ -3,12 +3,29 @@ use std::iter;
 use std::str::FromStr;
 
 use anyhow::Error;
+use common::{Answer, Solution};
 
-pub fn solve(input: String) {
-    let (seeds, maps) = parse_input(input);
+common::register!(Day05, year: 2023, day: 5, title: "If You Give A Seed A Fertilizer");
 
-    println!("{}", part1(&seeds, &maps));
-    println!("{}", part2(&seeds, &maps));
+pub struct Day05 {
+    seeds: Vec<usize>,
+    maps: Vec<RangeMap>
+}
+
+impl Solution for Day05 {
+    fn parse(input: &str) -> Self {
+        let (seeds, maps) = parse_input(input.to_owned());
+
+        Day05 { seeds, maps }
+    }
+
+    fn part1(&self) -> Answer {
+        part1(&self.seeds, &self.maps).into()
+    }
+
+    fn part2(&self) -> Answer {
+        part2(&self.seeds, &self.maps).into()
+    }
 }
 
 fn parse_input(input: String) -> (Vec<usize>, Vec<RangeMap>) {
@@ -25,11 +42,11 @@ fn parse_input(input: String) -> (Vec<usize>, Vec<RangeMap>) {
     (seeds, maps)
 }
 
-fn part1(seeds: &Vec<usize>, maps: &Vec<RangeMap>) -> usize {
+fn part1(seeds: &[usize], maps: &[RangeMap]) -> usize {
     seeds.iter().map(|s| maps.iter().fold(*s, |acc, m| m.destination(acc))).min().unwrap()
 }
 
-fn part2(seeds: &Vec<usize>, maps: &Vec<RangeMap>) -> usize {
+fn part2(seeds: &[usize], maps: &[RangeMap]) -> usize {
     let seed_ranges = RangeMap {
         map: seeds
         .windows(2)
@@ -130,7 +147,7 @@ impl Range {
                 merged: Some(merged),
                 unused_sources: Box::new(iter::once(unused))
             }
-        } else if self.is_contained(&other) {
+        } else if self.is_contained(other) {
             let prefix_length = self.source_start - other.target_start;
             let prefix = Range {
                 source_start: other.source_start,
@@ -209,16 +226,13 @@ impl RangeMap {
             for next in &next_level.map {
                 let partial_result = next.merge(&prev);
 
-                match partial_result.merged {
-                    Some(r) => {
-                        if r.length != 0 {
-                            result.push(r);
-                            partial_result.unused_sources.for_each(|r| queue.push_back(r));
-                            found = true;
-                            break;
-                        }
-                    },
-                    None => {}
+                if let Some(r) = partial_result.merged {
+                    if r.length != 0 {
+                        result.push(r);
+                        partial_result.unused_sources.for_each(|r| queue.push_back(r));
+                        found = true;
+                        break;
+                    }
                 }
             }
 
@@ -1,20 +1,35 @@
 use std::iter::zip;
 
 use anyhow::Error;
+use common::{Answer, Solution};
 
-pub fn solve(input: String) {
-    let races = parse_input(input);
-    println!("{}", part1(&races));
-    println!("{}", part2(&races));
+common::register!(Day06, year: 2023, day: 6, title: "Wait For It");
+
+pub struct Day06 {
+    races: Vec<Race>
+}
+
+impl Solution for Day06 {
+    fn parse(input: &str) -> Self {
+        Day06 { races: parse_input(input.to_owned()) }
+    }
+
+    fn part1(&self) -> Answer {
+        part1(&self.races).into()
+    }
+
+    fn part2(&self) -> Answer {
+        part2(&self.races).into()
+    }
 }
 
-fn part1(races: &Vec<Race>) -> usize {
+fn part1(races: &[Race]) -> usize {
     races.iter()
         .map(Race::ways_to_win)
-        .fold(1, |a, b| a * b)
+        .product()
 }
 
-fn part2(races: &Vec<Race>) -> usize {
+fn part2(races: &[Race]) -> usize {
     races.iter()
         .map(Race::clone)
         .reduce(|r1, r2| Race { time: r1.time * 100 + r2.time, distance: r1.distance * 10000 + r2.distance })
@@ -26,7 +41,7 @@ fn parse_input(input: String) -> Vec<Race> {
     let (times, distances) = input.split_once("\n").expect("Invalid input format");
     let times = parse_line(times).unwrap();
     let distances = parse_line(distances).unwrap();
-    zip(times.into_iter(), distances.into_iter())
+    zip(times, distances)
         .map(|(time, distance)| Race { time, distance })
         .collect()
 }
@@ -1,11 +1,28 @@
 use std::str::FromStr;
 
 use anyhow::Error;
+use common::{Answer, Solution};
 use counter::Counter;
 
-pub fn solve(input: String) {
-    println!("{}", winnings(&parse_input_1(&input)));
-    println!("{}", winnings(&parse_input_2(&input)));
+common::register!(Day07, year: 2023, day: 7, title: "Camel Cards");
+
+pub struct Day07 {
+    bids: Vec<Bid>,
+    bids_with_jokers: Vec<Bid>
+}
+
+impl Solution for Day07 {
+    fn parse(input: &str) -> Self {
+        Day07 { bids: parse_input_1(input), bids_with_jokers: parse_input_2(input) }
+    }
+
+    fn part1(&self) -> Answer {
+        winnings(&self.bids).into()
+    }
+
+    fn part2(&self) -> Answer {
+        winnings(&self.bids_with_jokers).into()
+    }
 }
 
 fn parse_input_1(input: &str) -> Vec<Bid> {
@@ -17,19 +34,19 @@ fn parse_input_1(input: &str) -> Vec<Bid> {
 
 fn parse_input_2(input: &str) -> Vec<Bid> {
     input.lines()
-        .map(|l| Bid::from_str_with_jokers(l))
+        .map(Bid::from_str_with_jokers)
         .collect::<Result<Vec<_>, _>>()
         .expect("Invalid input format")
 }
 
-fn winnings(bids: &Vec<Bid>) -> usize {
-    let mut bids = bids.clone();
+fn winnings(bids: &[Bid]) -> usize {
+    let mut bids = bids.to_vec();
     bids.sort_by(|a, b| a.hand.cmp(&b.hand));
     bids.into_iter().enumerate().map(|(i, b)| (i + 1) * b.amount).sum()
 }
 
 fn card_value(card: char, use_jokers: bool) -> Option<usize> {
-    if card.is_digit(10) && card > '1' {
+    if card.is_ascii_digit() && card > '1' {
         return Some(card as usize - '0' as usize);
     }
 
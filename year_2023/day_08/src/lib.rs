@@ -0,0 +1,239 @@
+use std::{str::FromStr, collections::HashMap};
+
+use anyhow::Error;
+use common::math::crt;
+use common::{Answer, Solution};
+
+common::register!(Day08, year: 2023, day: 8, title: "Haunted Wasteland");
+
+pub struct Day08 {
+    directions: Vec<char>,
+    map: HashMap<String, Node>
+}
+
+impl Solution for Day08 {
+    fn parse(input: &str) -> Self {
+        let (directions, map) = parse_input(input).unwrap();
+
+        Day08 { directions, map }
+    }
+
+    fn part1(&self) -> Answer {
+        part1(&self.directions, &self.map).into()
+    }
+
+    fn part2(&self) -> Answer {
+        part2(&self.directions, &self.map).into()
+    }
+}
+
+fn parse_input(input: &str) -> Result<(Vec<char>, HashMap<String, Node>), Error> {
+    let mut lines = input.lines();
+    let directions = lines.next().ok_or(Error::msg("Invalid input"))?.chars().collect();
+    let nodes = lines.skip(1).map(|l| l.parse::<Node>()).collect::<Result<Vec<Node>, _>>()?;
+    let map = nodes.into_iter().map(|n| (n.label.clone(), n)).collect::<HashMap<_, _>>();
+
+    Ok((directions, map))
+}
+
+fn part1(directions: &[char], map: &HashMap<String, Node>) -> usize {
+    end_times(directions, map, |s| s == "AAA", |s| s == "ZZZ").next().unwrap()
+}
+
+fn part2(directions: &[char], map: &HashMap<String, Node>) -> usize {
+    let ghosts: Vec<EndTimes> = map.keys().filter(|k| k.ends_with('A')).map(|k| ghost_end_times(directions, map, k)).collect();
+    let settled = ghosts.iter().map(|g| g.start).max().unwrap();
+
+    // before the last ghost enters its cycle
+    if let Some(time) = (1..settled).find(|&t| ghosts.iter().all(|g| g.contains(t))) {
+        return time;
+    }
+
+    // afterwards every ghost has to be at one of the end nodes in its cycle
+    let congruences = ghosts.iter().fold(vec![(0i64, 1i64)], |acc, g| {
+        acc.iter()
+            .flat_map(|&c| g.offsets.iter().filter_map(move |&o| crt(&[c, ((g.start + o) as i64, g.length as i64)])))
+            .collect()
+    });
+
+    congruences.into_iter()
+        .map(|(remainder, modulus)| {
+            let (remainder, modulus) = (remainder as usize, modulus as usize);
+
+            remainder + settled.saturating_sub(remainder).div_ceil(modulus) * modulus
+        })
+        .min()
+        .expect("Ghosts never meet at end nodes")
+}
+
+// a ghost is at an end node at every time in `transient` and, from `start` on, at every time t
+// with (t - start) % length in `offsets`
+struct EndTimes {
+    transient: Vec<usize>,
+    start: usize,
+    length: usize,
+    offsets: Vec<usize>
+}
+
+impl EndTimes {
+    fn contains(&self, time: usize) -> bool {
+        if time < self.start {
+            self.transient.contains(&time)
+        } else {
+            self.offsets.contains(&((time - self.start) % self.length))
+        }
+    }
+}
+
+// walks until a (node, instruction index) state repeats, which is where the ghost's cycle starts
+fn ghost_end_times(directions: &[char], map: &HashMap<String, Node>, start: &str) -> EndTimes {
+    let mut seen = HashMap::new();
+    let mut ends = Vec::new();
+    let mut node = start;
+    let mut time = 0;
+
+    loop {
+        let instruction = time % directions.len();
+
+        if let Some(&first) = seen.get(&(node, instruction)) {
+            let (transient, cycle): (Vec<usize>, Vec<usize>) = ends.into_iter().partition(|&t| t < first);
+
+            return EndTimes { transient, start: first, length: time - first, offsets: cycle.into_iter().map(|t| t - first).collect() };
+        }
+
+        seen.insert((node, instruction), time);
+        if node.ends_with('Z') {
+            ends.push(time);
+        }
+
+        node = map.get(node).unwrap_or_else(|| panic!("Invalid node {node}")).get_next(directions[instruction]).expect("Invalid direction");
+        time += 1;
+    }
+}
+
+fn end_times<'a, P1, P2>(directions: &'a [char], map: &'a HashMap<String, Node>, start: P1, end: P2) -> impl Iterator<Item = usize> + 'a
+    where P1: Fn(&str) -> bool, P2: Fn(&str) -> bool + 'a {
+    
+    let start_node = (*map.keys().find(|x| start(x)).unwrap()).clone();
+    directions.iter().cycle().scan(start_node, |state, direction| {
+        *state = map.get(state).unwrap_or_else(|| panic!("Invalid node {}", *state)).get_next(*direction).expect("Invalid direction").to_owned();
+        Some(state.clone())
+    })
+    .enumerate()
+    .filter(move |(_, n)| end(n))
+    .map(|(i, _)| i + 1)
+}
+
+struct Node {
+    label: String,
+    left: String,
+    right: String
+}
+
+impl FromStr for Node {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (label, edges) = s.split_once(" = ").ok_or(Error::msg("Invalid entry format"))?;
+        let (left, right) = edges[1..edges.len() - 1].split_once(", ").ok_or(Error::msg("Invalid edges format"))?;
+
+        Ok(Node { label: label.to_owned(), left: left.to_owned(), right: right.to_owned() })
+    }
+}
+
+impl Node {
+    fn get_next(&self, next: char) -> Option<&str> {
+        match next {
+            'L' => Some(&self.left),
+            'R' => Some(&self.right),
+            _   => None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn example1_part1() {
+        let (directions, map) = parse_input(EXAPLE_INPUT_1).unwrap();
+        assert_eq!(part1(&directions, &map), 2);
+    }
+
+    #[test]
+    fn example2_part1() {
+        let (directions, map) = parse_input(EXAPLE_INPUT_2).unwrap();
+        assert_eq!(part1(&directions, &map), 6);
+    }
+
+    #[test]
+    fn example_part2() {
+        let (directions, map) = parse_input(EXAPLE_INPUT_3).unwrap();
+        assert_eq!(part2(&directions, &map), 6);
+    }
+
+    #[test]
+    fn test_part2_offsets() {
+        let (directions, map) = parse_input(OFFSETS_INPUT).unwrap();
+        assert_eq!(part2(&directions, &map), 5);
+    }
+
+    #[test]
+    fn test_part2_uneven_cycle() {
+        let (directions, map) = parse_input(UNEVEN_CYCLE_INPUT).unwrap();
+        assert_eq!(part2(&directions, &map), 7);
+    }
+
+    const EXAPLE_INPUT_1: &str = "RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)";
+
+const EXAPLE_INPUT_2: &str = "LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+
+const EXAPLE_INPUT_3: &str = "LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)";
+
+const OFFSETS_INPUT: &str = "L
+
+11A = (11B, 11B)
+11B = (11Z, 11Z)
+11Z = (11C, 11C)
+11C = (11D, 11D)
+11D = (11Z, 11Z)
+22A = (22Z, 22Z)
+22Z = (22B, 22B)
+22B = (22Z, 22Z)";
+
+// the first ghost's cycle passes two end nodes in a row, so its first two end times are 1 apart but it
+// isn't at an end node at times 3 and 6
+const UNEVEN_CYCLE_INPUT: &str = "L
+
+11A = (11Z, 11Z)
+11Z = (12Z, 12Z)
+12Z = (11B, 11B)
+11B = (11Z, 11Z)
+22A = (22B, 22B)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22D, 22D)
+22D = (22B, 22B)";
+}
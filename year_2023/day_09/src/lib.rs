@@ -1,10 +1,24 @@
 use anyhow::Error;
+use common::{Answer, Solution};
 
-pub fn solve(input: String) {
-    let lines = parse_input(&input);
+common::register!(Day09, year: 2023, day: 9, title: "Mirage Maintenance");
 
-    println!("{}", part1(&lines));
-    println!("{}", part2(&lines));
+pub struct Day09 {
+    lines: Vec<Vec<isize>>
+}
+
+impl Solution for Day09 {
+    fn parse(input: &str) -> Self {
+        Day09 { lines: parse_input(input) }
+    }
+
+    fn part1(&self) -> Answer {
+        part1(&self.lines).into()
+    }
+
+    fn part2(&self) -> Answer {
+        part2(&self.lines).into()
+    }
 }
 
 fn parse_input(input: &str) -> Vec<Vec<isize>> {
@@ -16,27 +30,27 @@ fn parse_input(input: &str) -> Vec<Vec<isize>> {
 
 fn parse_line(line: &str) -> Result<Vec<isize>, Error> {
     line.split(" ")
-        .map(|x| x.parse().map_err(|e| Error::from(e)))
+        .map(|x| x.parse().map_err(Error::from))
         .collect()
 }
 
-fn part1(lines: &Vec<Vec<isize>>) -> isize {
-    lines.iter().map(next_value).sum()
+fn part1(lines: &[Vec<isize>]) -> isize {
+    lines.iter().map(|line| next_value(line)).sum()
 }
 
-fn part2(lines: &Vec<Vec<isize>>) -> isize {
-    lines.iter().map(previous_value).sum()
+fn part2(lines: &[Vec<isize>]) -> isize {
+    lines.iter().map(|line| previous_value(line)).sum()
 }
 
-fn next_value(row: &Vec<isize>) -> isize {
+fn next_value(row: &[isize]) -> isize {
     if row.iter().all(|x| x == &0) {
         0
     } else {
-        row.last().unwrap() + next_value(&differences(&row))
+        row.last().unwrap() + next_value(&differences(row))
     }
 }
 
-fn previous_value(row: &Vec<isize>) -> isize {
+fn previous_value(row: &[isize]) -> isize {
     if row.iter().all(|x| x == &0) {
         0
     } else {
@@ -44,7 +58,7 @@ fn previous_value(row: &Vec<isize>) -> isize {
     }
 }
 
-fn differences(row: &Vec<isize>) -> Vec<isize> {
+fn differences(row: &[isize]) -> Vec<isize> {
     row.windows(2).map(|a| a[1] - a[0]).collect()
 }
 
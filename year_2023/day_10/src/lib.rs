@@ -0,0 +1,256 @@
+/*
+WARNING: the code that follows will make you cry:
+            A safety pig has been provided for your benefit
+
+                         _
+ _._ _..._ .-',     _.._(`))
+'-. `     '  /-._.-'    ',/
+   )         \            '.
+  / _    _    |             \
+ |  a    a    /              |
+ \   .-.                     ;  
+  '-('' ).-'       ,'       ;
+     '-;           |      .'
+        \           \    /
+        | 7  .__  _.-\   \
+        | |  |  ``/  /`  /
+       /,_|  |   /,_/   /
+          /,_/      '`-'
+*/
+
+use std::collections::HashSet;
+use std::iter::successors;
+use std::rc::Rc;
+use std::str::FromStr;
+
+use anyhow::Error;
+use common::geometry::{Direction4::{self, *}, Point};
+use common::grid::Grid;
+use common::{Answer, Solution};
+
+common::register!(Day10, year: 2023, day: 10, title: "Pipe Maze");
+
+pub struct Day10 {
+    maze: Maze
+}
+
+impl Solution for Day10 {
+    fn parse(input: &str) -> Self {
+        Day10 { maze: input.parse::<Maze>().expect("Couldn't parse input") }
+    }
+
+    fn part1(&self) -> Answer {
+        part1(&self.maze).into()
+    }
+
+    fn part2(&self) -> Answer {
+        part2(&self.maze).into()
+    }
+}
+
+fn part1(maze: &Maze) -> usize {
+    successors(Some(2usize), |x| Some(x + 1)).scan(maze.clone(), |state, x| {
+        if state.step() {
+            None
+        } else {
+            Some(x)
+        }
+    }).last().unwrap().div_ceil(2)
+}
+
+fn part2(maze: &Maze) -> usize {
+    let (loop_length, inflated) = maze.clone().inflate();
+    let outside_nodes_count = flood_fill_count(&inflated);
+
+    (maze.map.width() * maze.map.height()) - loop_length - outside_nodes_count
+}
+
+#[derive(Clone)]
+struct Maze {
+    map: Rc<Grid<char>>,
+    current_position: Point<usize>,
+    last_position: Point<usize>
+}
+
+impl FromStr for Maze {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let map: Grid<char> = s.parse()?;
+        let last_position = map.position(|&c| c == 'S').ok_or(Error::msg("No starting position in the map"))?;
+
+        let connects = |direction: Direction4, pipes: [char; 3]| map.offset(last_position, direction).filter(|&p| pipes.contains(&map[p]));
+
+        let current_position = connects(UP, ['|', '7', 'F'])
+            .or_else(|| connects(DOWN, ['|', 'L', 'J']))
+            .or_else(|| connects(LEFT, ['-', 'L', 'F']))
+            .or_else(|| connects(RIGHT, ['-', 'J', '7']))
+            .ok_or(Error::msg("No valid path found"))?;
+
+        Ok(Maze { map: Rc::new(map), current_position, last_position })
+    }
+}
+
+impl Maze {
+    fn get(&self, position: Point<usize>) -> Option<char> {
+        self.map.get(position).copied()
+    }
+
+    fn step(&mut self) -> bool {
+        let directions = match self.get(self.current_position).unwrap() {
+            '|' => [UP, DOWN],
+            '-' => [LEFT, RIGHT],
+            'L' => [UP, RIGHT],
+            'J' => [UP, LEFT],
+            '7' => [DOWN, LEFT],
+            'F' => [DOWN, RIGHT],
+            _   => panic!("Invalid character {} at position {:?}", self.get(self.current_position).unwrap(), self.current_position)
+        };
+
+        for m in directions.map(|d| self.current_position.checked_offset(d).unwrap()) {
+            if m != self.last_position {
+                self.last_position = self.current_position;
+                self.current_position = m;
+                break;
+            }
+        }
+
+        self.get(self.current_position).unwrap() == 'S'
+    }
+
+    /*
+        ......
+        .F--7.
+        .|..|.
+        .L--J.
+        ......
+
+        ooooooooooooo
+        o.o.o.o.o.o.o
+        ooooooooooooo
+        o.oxxxxxxxo.o
+        oooxoooooxooo
+        o.oxo.o.oxo.o
+        oooxoooooxooo
+        o.oxxxxxxxo.o
+        ooooooooooooo
+        o.o.o.o.o.o.o
+        ooooooooooooo
+     */
+    fn inflate(mut self) -> (usize, Grid<char>) {
+        let mut loop_elements: HashSet<Point<usize>> = HashSet::new();
+        loop_elements.insert(self.last_position);
+        loop_elements.insert(self.current_position);
+
+        while !self.step() {
+            loop_elements.insert(self.current_position);
+        }
+
+        let mut result = Grid::filled(2 * self.map.width() + 1, 2 * self.map.height() + 1, 'o');
+
+        for (p, c1) in self.map.iter() {
+            let (right, down) = (p + Point::new(1, 0), p + Point::new(0, 1));
+            let inflated = p * 2 + Point::new(1, 1);
+
+            result[inflated] = if loop_elements.contains(&p) { 'x' } else { '.' };
+
+            if self.get(right).is_some_and(|c2| ['-', 'J', '7', 'S'].contains(&c2)) && ['-', 'L', 'F', 'S'].contains(c1)
+                && loop_elements.contains(&p) && loop_elements.contains(&right) {
+
+                result[inflated + Point::new(1, 0)] = 'x';
+            }
+
+            if self.get(down).is_some_and(|c2| ['|', 'L', 'J', 'S'].contains(&c2)) && ['|', 'F', '7', 'S'].contains(c1)
+                && loop_elements.contains(&p) && loop_elements.contains(&down) {
+
+                result[inflated + Point::new(0, 1)] = 'x';
+            }
+        }
+
+        (loop_elements.len(), result)
+    }
+}
+
+fn flood_fill_count(map: &Grid<char>) -> usize {
+    let mut stack: Vec<Point<usize>> = Vec::new();
+    let mut visited: HashSet<Point<usize>> = HashSet::new();
+    let mut result = 0;
+
+    stack.push(Point::new(0, 0));
+    visited.insert(Point::new(0, 0));
+
+    while let Some(p) = stack.pop() {
+        if map[p] == '.' {
+            result += 1;
+        }
+
+        for neighbour in map.neighbours4(p) {
+            if map[neighbour] != 'x' && !visited.contains(&neighbour) {
+                visited.insert(neighbour);
+                stack.push(neighbour);
+            }
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn example_part1() {
+        let maze = EXAMPLE1_INPUT.parse::<Maze>().unwrap();
+
+        assert_eq!(part1(&maze), 8);
+    }
+
+    #[test]
+    fn example_part2() {
+        let maze = EXAMPLE2_INPUT.parse::<Maze>().unwrap();
+
+        assert_eq!(part2(&maze), 10);
+    }
+
+    #[test]
+    fn test_inflate() {
+        let maze = EXAMPLE_INFLATE.parse::<Maze>().unwrap();
+        assert_eq!(maze.inflate().1.to_string(), EXAMPLE_INFLATE_RESULT);
+    }
+
+    const EXAMPLE1_INPUT: &str = "..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ...";
+
+    const EXAMPLE2_INPUT: &str = "FF7F7F7F7F7F7F7F---7
+L|LJS|||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJIF7FJ-
+L---JF-JLJIIIIFJLJJ7
+|F|F-JF---7IIIL7L|7|
+|FFJF7L7F-JF7IIL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L";
+
+    const EXAMPLE_INFLATE: &str = "......
+.S--7.
+.|..|.
+.L--J.
+......";
+
+    const EXAMPLE_INFLATE_RESULT: &str = "ooooooooooooo
+o.o.o.o.o.o.o
+ooooooooooooo
+o.oxxxxxxxo.o
+oooxoooooxooo
+o.oxo.o.oxo.o
+oooxoooooxooo
+o.oxxxxxxxo.o
+ooooooooooooo
+o.o.o.o.o.o.o
+ooooooooooooo";
+}
\ No newline at end of file
@@ -0,0 +1,82 @@
+use common::geometry::Point;
+use common::grid::Grid;
+use common::{Answer, Solution};
+
+common::register!(Day11, year: 2023, day: 11, title: "Cosmic Expansion");
+
+pub struct Day11 {
+    galaxies1: Vec<Point<isize>>,
+    galaxies2: Vec<Point<isize>>
+}
+
+impl Solution for Day11 {
+    fn parse(input: &str) -> Self {
+        Day11 { galaxies1: parse_input(input, 2), galaxies2: parse_input(input, 1000000) }
+    }
+
+    fn part1(&self) -> Answer {
+        total_distances(&self.galaxies1).into()
+    }
+
+    fn part2(&self) -> Answer {
+        total_distances(&self.galaxies2).into()
+    }
+}
+
+fn parse_input(input: &str, expansion: isize) -> Vec<Point<isize>> {
+    let map: Grid<char> = input.parse().expect("Couldn't parse input");
+    let mut galaxies: Vec<Point<isize>> = map.iter()
+        .filter(|(_, c)| **c == '#')
+        .map(|(p, _)| Point::new(p.x as isize, p.y as isize))
+        .collect();
+
+    let expanded_rows: Vec<isize> = map.rows().enumerate()
+        .filter(|(_, r)| r.iter().all(|c| *c == '.'))
+        .map(|(y, _)| y as isize)
+        .collect();
+
+    let expanded_cols: Vec<isize> = map.columns()
+        .map(|mut r| r.all(|c| *c == '.'))
+        .enumerate()
+        .filter(|(_, b)| *b)
+        .map(|(y, _)| y as isize)
+        .collect();
+
+    galaxies.iter_mut().for_each(|Point { x, y }| {
+        let vdelta = expanded_rows.iter().filter(|row| *y > **row).count() as isize * (expansion - 1);
+        let hdelta = expanded_cols.iter().filter(|col| *x > **col).count() as isize * (expansion - 1);
+        *x += hdelta;
+        *y += vdelta;
+    });
+
+    galaxies
+}
+
+fn total_distances(galaxies: &[Point<isize>]) -> isize {
+    galaxies.iter().enumerate()
+        .map(|(i, g1)| galaxies.iter().skip(i + 1).map(|g2| g1.manhattan(*g2)).sum::<isize>())
+        .sum()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn example_part1() {
+        let galaxies = parse_input(EXAMPLE_INPUT, 2);
+        assert_eq!(total_distances(&galaxies), 374);
+    }
+
+    const EXAMPLE_INPUT: &str = "...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+}
\ No newline at end of file
@@ -1,37 +1,49 @@
-#![feature(iter_intersperse)]
-#![feature(iter_repeat_n)]
-
 use std::iter::repeat_n;
 use std::str::FromStr;
 
 use anyhow::Error;
+use common::iter::IteratorExt;
+use common::{Answer, Solution};
+
+common::register!(Day12, year: 2023, day: 12, title: "Hot Springs");
+
+pub struct Day12 {
+    rows: Vec<Row>
+}
 
-pub fn solve(input: String) {
-    let rows = parse_input(&input);
+impl Solution for Day12 {
+    fn parse(input: &str) -> Self {
+        Day12 { rows: parse_input(input) }
+    }
+
+    fn part1(&self) -> Answer {
+        part1(&self.rows).into()
+    }
 
-    println!("{}", part1(&rows));
-    println!("{}", part2(&rows));
+    fn part2(&self) -> Answer {
+        part2(&self.rows).into()
+    }
 }
 
 fn parse_input(input: &str) -> Vec<Row> {
     input.lines().map(|l| l.parse()).collect::<Result<_, _>>().expect("Invalid input format")
 }
 
-fn part1(rows: &Vec<Row>) -> usize {
+fn part1(rows: &[Row]) -> usize {
     // rows.iter().map(possible_arrangements).sum()
     calculate_answer(rows)
 }
 
-fn part2(rows: &Vec<Row>) -> usize {
-    calculate_answer(&rows.iter().map(Row::unfolded).collect())
+fn part2(rows: &[Row]) -> usize {
+    calculate_answer(&rows.iter().map(Row::unfolded).collect::<Vec<_>>())
 }
 
-fn calculate_answer(rows: &Vec<Row>) -> usize {
+fn calculate_answer(rows: &[Row]) -> usize {
     rows.iter().map(|row| possible_arrangements_dp(row.damaged_record.as_ref(), &row.counts, &mut vec![vec![None; row.damaged_record.len() + 1]; row.counts.len() + 1])).sum()
 }
 
 fn possible_arrangements_dp(record: &str, counts: &[usize], dp: &mut Vec<Vec<Option<usize>>>) -> usize {
-    if counts.len() == 0 {
+    if counts.is_empty() {
         return if record.chars().any(|x| x == '#') {
             0
         } else {
@@ -39,11 +51,11 @@ fn possible_arrangements_dp(record: &str, counts: &[usize], dp: &mut Vec<Vec<Opt
         };
     }
 
-    if let None = dp[counts.len()][record.len()] {
+    if dp[counts.len()][record.len()].is_none() {
         let n = counts[0];
         let mut result = 0;
 
-        for i in 0..record.len() - (counts.len() - 1 + &counts[1..].iter().sum()) - n + 1 {
+        for i in 0..record.len() - (counts.len() - 1 + counts[1..].iter().sum::<usize>()) - n + 1 {
             if record.chars().nth(i + n) != Some('#') && record[i..i+n].chars().all(|x| x != '.') {
                 result += possible_arrangements_dp(&record[(i+n+1).min(record.len())..], &counts[1..], dp);
             }
@@ -66,12 +78,12 @@ fn possible_arrangements(row: &Row) -> usize {
         let char = if ((i >> shift) & 1) == 1 { "#" } else { "." };
         record.replacen('?', char, 1)
     }))
-    .filter(|r| is_correct(&r, &row.counts))
+    .filter(|r| is_correct(r, &row.counts))
     .count()
 }
 
-fn is_correct(record: &str, numbers: &Vec<usize>) -> bool {
-    record.split('.').map(str::len).filter(|x| x != &0).eq(numbers.iter().map(|x| *x))
+fn is_correct(record: &str, numbers: &[usize]) -> bool {
+    record.split('.').map(str::len).filter(|x| x != &0).eq(numbers.iter().copied())
 }
 
 #[derive(Debug)]
@@ -94,7 +106,7 @@ impl FromStr for Row {
 
 impl Row {
     fn unfolded(&self) -> Self {
-        let damaged_record = repeat_n(self.damaged_record.clone(), 5).intersperse("?".to_owned()).collect();
+        let damaged_record = repeat_n(self.damaged_record.clone(), 5).interspersed("?".to_owned()).collect();
         let counts = self.counts.iter().map(Clone::clone).cycle().take(self.counts.len() * 5).collect::<Vec<usize>>();
 
         Self { damaged_record, counts }
@@ -107,7 +119,7 @@ mod tests {
 
     #[test]
     fn test_is_correct() {
-        assert!(is_correct("#.#.###", &vec![1,1,3]));
+        assert!(is_correct("#.#.###", &[1,1,3]));
     }
 
     #[test]
@@ -117,7 +129,7 @@ mod tests {
 
     #[test]
     fn test_possible_arrangements_dp() {
-        assert_eq!(possible_arrangements_dp("???#.?.#??.?##?????#", &vec![2,3,2,3], &mut vec![vec![None; 21]; 5]), 1);
+        assert_eq!(possible_arrangements_dp("???#.?.#??.?##?????#", &[2,3,2,3], &mut vec![vec![None; 21]; 5]), 1);
     }
 
     #[test]
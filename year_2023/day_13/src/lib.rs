@@ -0,0 +1,98 @@
+use common::grid::Grid;
+use common::{Answer, Solution};
+
+common::register!(Day13, year: 2023, day: 13, title: "Point of Incidence");
+
+pub struct Day13 {
+    maps: Vec<Grid<char>>
+}
+
+impl Solution for Day13 {
+    fn parse(input: &str) -> Self {
+        Day13 { maps: parse_input(input) }
+    }
+
+    fn part1(&self) -> Answer {
+        get_solution(&self.maps, 1).into()
+    }
+
+    fn part2(&self) -> Answer {
+        get_solution(&self.maps, 2).into()
+    }
+}
+
+fn get_solution(maps: &[Grid<char>], part: usize) -> usize {
+    maps.iter().map(|m| value(m, part - 1)).sum()
+}
+
+fn parse_input(input: &str) -> Vec<Grid<char>> {
+    input.split("\n\n").map(|block| block.parse().expect("Couldn't parse input")).collect()
+}
+
+fn value(map: &Grid<char>, expected_distance: usize) -> usize {
+    if let Some(col) = (1..map.height()).find(|col| is_reflection_line(map, *col, expected_distance)) {
+        100 * col
+    } else {
+        let transposed = map.transposed();
+        (1..transposed.height()).find(|row| is_reflection_line(&transposed, *row, expected_distance)).unwrap()
+    }
+}
+
+fn is_reflection_line(map: &Grid<char>, line: usize, expected_distance: usize) -> bool {
+    map.rows().take(line).rev().zip(map.rows().skip(line)).map(|(r1, r2)| distance(r1, r2)).sum::<usize>() == expected_distance
+}
+
+fn distance(lhs: &[char], rhs: &[char]) -> usize {
+    lhs.iter().zip(rhs).filter(|(a, b)| a != b).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_is_reflection_line() {
+        let map = &parse_input(EXAMPLE_INPUT)[1];
+        assert!(is_reflection_line(map, 4, 0));
+    }
+
+    #[test]
+    fn test_value_vertical() {
+        let map = &parse_input(EXAMPLE_INPUT)[0];
+        assert_eq!(value(map, 0), 5);
+    }
+
+    #[test]
+    fn test_value_horizontal() {
+        let map = &parse_input(EXAMPLE_INPUT)[1];
+        assert_eq!(value(map, 0), 400);
+    }
+
+    #[test]
+    fn example_part1() {
+        let maps = parse_input(EXAMPLE_INPUT);
+        assert_eq!(get_solution(&maps, 1), 405);
+    }
+
+    #[test]
+    fn example_part2() {
+        let maps = parse_input(EXAMPLE_INPUT);
+        assert_eq!(get_solution(&maps, 2), 400);
+    }
+
+    const EXAMPLE_INPUT: &str = "#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#";
+}
\ No newline at end of file
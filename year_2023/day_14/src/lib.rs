@@ -0,0 +1,141 @@
+use std::collections::HashMap;
+
+use common::geometry::Point;
+use common::grid::Grid;
+use common::{Answer, Solution};
+
+common::register!(Day14, year: 2023, day: 14, title: "Parabolic Reflector Dish");
+
+pub struct Day14 {
+    map: Grid<char>
+}
+
+impl Solution for Day14 {
+    fn parse(input: &str) -> Self {
+        Day14 { map: parse_input(input) }
+    }
+
+    fn part1(&self) -> Answer {
+        part1(&self.map).into()
+    }
+
+    fn part2(&self) -> Answer {
+        part2(&self.map).into()
+    }
+}
+
+fn parse_input(input: &str) -> Grid<char> {
+    input.parse().expect("Couldn't parse input")
+}
+
+fn part1(map: &Grid<char>) -> usize {
+    let mut map = map.clone();
+    
+    roll_north(&mut map);
+    total_load(&map)
+}
+
+fn part2(map: &Grid<char>) -> usize {
+    let mut map = map.clone();
+    let mut seen_states: HashMap<Vec<Point<usize>>, usize> = HashMap::new();
+    let mut i = 0;
+
+    seen_states.insert(get_state(&map), 0);
+
+    while i < 1000000000 - 1 {
+        cycle(&mut map);
+        let state = get_state(&map);
+
+        if let Some(&seen) = seen_states.get(&state) {
+            let skip = i - seen + 1;
+            while i + skip < 1000000000 {
+                i += skip;
+            }
+            seen_states.clear();
+        } else {
+            i += 1;
+            seen_states.insert(state, i);
+        }
+    }
+
+    total_load(&map)
+}
+
+fn get_state(map: &Grid<char>) -> Vec<Point<usize>> {
+    map.iter().filter(|(_, c)| c == &&'O').map(|(p, _)| p).collect()
+}
+
+// rolls north, west, south and east by rotating the map so that each direction faces north in turn
+fn cycle(map: &mut Grid<char>) {
+    for _ in 0..4 {
+        roll_north(map);
+        *map = map.rotated_clockwise();
+    }
+}
+
+fn roll_north(map: &mut Grid<char>) {
+    let mut min_positions: Vec<usize> = (0..map.width()).map(|x| map.column(x).position(|c| c != &'#').unwrap_or(map.height())).collect();
+
+    for y in 0..map.height() {
+        for x in 0..map.width() {
+            match map[Point::new(x, y)] {
+                'O' => {
+                    map[Point::new(x, y)] = '.';
+                    map[Point::new(x, min_positions[x])] = 'O';
+                    min_positions[x] += 1;
+                },
+                '#' => {
+                    min_positions[x] = y + 1;
+                },
+                _ => {}
+            };
+        }
+    }
+}
+
+fn total_load(map: &Grid<char>) -> usize {
+    let h = map.height();
+
+    map.iter().map(|(p, c)| if c == &'O' { h - p.y } else { 0 }).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn example_part1() {
+        assert_eq!(part1(&parse_input(EXAMPLE_INPUT)), 136);
+    }
+
+    #[test]
+    fn example_part2() {
+        assert_eq!(part2(&parse_input(EXAMPLE_INPUT)), 64);
+    }
+
+    #[test]
+    fn test_cycle() {
+        let mut map = parse_input(EXAMPLE_INPUT);
+        cycle(&mut map);
+        assert_eq!(dumps(&map), ".....#....\n....#...O#\n...OO##...\n.OO#......\n.....OOO#.\n.O#...O#.#\n....O#....\n......OOOO\n#...O###..\n#..OO#....\n");
+        cycle(&mut map);
+        assert_eq!(dumps(&map), ".....#....\n....#...O#\n.....##...\n..O#......\n.....OOO#.\n.O#...O#.#\n....O#...O\n.......OOO\n#..OO###..\n#.OOO#...O\n");
+        cycle(&mut map);
+        assert_eq!(dumps(&map), ".....#....\n....#...O#\n.....##...\n..O#......\n.....OOO#.\n.O#...O#.#\n....O#...O\n.......OOO\n#...O###.O\n#.OOO#...O\n");
+    }
+
+    fn dumps(map: &Grid<char>) -> String {
+        format!("{map}\n")
+    }
+
+    const EXAMPLE_INPUT: &str = "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+}
\ No newline at end of file
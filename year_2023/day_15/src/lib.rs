@@ -1,23 +1,37 @@
 use std::str::FromStr;
 
 use anyhow::Error;
+use common::{Answer, Solution};
 
-pub fn solve(input: String) {
-    let instructions = parse_input(&input);
+common::register!(Day15, year: 2023, day: 15, title: "Lens Library");
 
-    println!("{}", part1(&instructions));
-    println!("{}", part2(&instructions));
+pub struct Day15 {
+    instructions: Vec<String>
 }
 
-fn parse_input(input: &str) -> Vec<&str> {
-    input.trim().split(",").collect()
+impl Solution for Day15 {
+    fn parse(input: &str) -> Self {
+        Day15 { instructions: parse_input(input) }
+    }
+
+    fn part1(&self) -> Answer {
+        part1(&self.instructions).into()
+    }
+
+    fn part2(&self) -> Answer {
+        part2(&self.instructions).into()
+    }
 }
 
-fn part1(instructions: &Vec<&str>) -> usize {
-    instructions.iter().map(|s| hash(*s)).sum()
+fn parse_input(input: &str) -> Vec<String> {
+    input.trim().split(",").map(str::to_owned).collect()
 }
 
-fn part2(instructions: &Vec<&str>) -> usize {
+fn part1(instructions: &[String]) -> usize {
+    instructions.iter().map(|s| hash(s)).sum()
+}
+
+fn part2(instructions: &[String]) -> usize {
     let mut hm = AOCHashMap::new();
 
     instructions.iter().map(|i| i.parse::<Operation>().expect("Invalid instruction"))
@@ -68,10 +82,9 @@ impl AOCHashMap {
     fn remove(&mut self, label: &str) {
         let h = hash(label);
         let pos = self.bins[h].iter().position(|(s, _)| s == label);
-        match pos {
-            Some(idx) => { self.bins[h].remove(idx); },
-            None => {}
-        };
+        if let Some(idx) = pos {
+            self.bins[h].remove(idx);
+        }
     }
 
     fn put(&mut self, label: &str, value: usize) {
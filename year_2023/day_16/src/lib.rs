@@ -1,15 +1,32 @@
 use std::collections::HashSet;
 
-pub fn solve(input: String) {
-    let contraption = parse_input(&input);
+use common::geometry::{Direction4::{self, *}, Point};
+use common::grid::Grid;
+use common::{Answer, Solution};
 
-    println!("{}", part1(&contraption));
-    println!("{}", part2(&contraption));
+common::register!(Day16, year: 2023, day: 16, title: "The Floor Will Be Lava");
+
+pub struct Day16 {
+    contraption: Contraption
+}
+
+impl Solution for Day16 {
+    fn parse(input: &str) -> Self {
+        Day16 { contraption: parse_input(input) }
+    }
+
+    fn part1(&self) -> Answer {
+        part1(&self.contraption).into()
+    }
+
+    fn part2(&self) -> Answer {
+        part2(&self.contraption).into()
+    }
 }
 
 fn parse_input(input: &str) -> Contraption {
     Contraption {
-        map: input.lines().map(|l| l.chars().collect()).collect()
+        map: input.parse().expect("Couldn't parse input")
     }
 }
 
@@ -18,90 +35,46 @@ fn part1(contraption: &Contraption) -> usize {
 }
 
 fn part2(contraption: &Contraption) -> usize {
-    let w = contraption.map[0].len();
-    let h = contraption.map.len();
+    let w = contraption.map.width();
+    let h = contraption.map.height();
 
-    (0..w).map(|x| contraption.simulate_beam(Beam { position: (x, 0), direction: DOWN })).max().unwrap().max(
-        (0..w).map(|x| contraption.simulate_beam(Beam { position: (x, h - 1), direction: UP })).max().unwrap()
+    (0..w).map(|x| contraption.simulate_beam(Beam { position: Point::new(x, 0), direction: DOWN })).max().unwrap().max(
+        (0..w).map(|x| contraption.simulate_beam(Beam { position: Point::new(x, h - 1), direction: UP })).max().unwrap()
     ).max(
-        (0..h).map(|y| contraption.simulate_beam(Beam { position: (0, y), direction: RIGHT })).max().unwrap()
+        (0..h).map(|y| contraption.simulate_beam(Beam { position: Point::new(0, y), direction: RIGHT })).max().unwrap()
     ).max(
-        (0..h).map(|y| contraption.simulate_beam(Beam { position: (w - 1, y), direction: LEFT })).max().unwrap()
+        (0..h).map(|y| contraption.simulate_beam(Beam { position: Point::new(w - 1, y), direction: LEFT })).max().unwrap()
     )
 }
 
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
-enum Direction {
-    UP,
-    DOWN,
-    LEFT,
-    RIGHT
-}
-
-use Direction::*;
-
-impl Direction {
-    fn next(&self) -> Self {
-        match self {
-            UP => RIGHT,
-            RIGHT => DOWN,
-            DOWN => LEFT,
-            LEFT => UP
-        }
-    }
-
-    fn prev(&self) -> Self {
-        match self {
-            UP => LEFT,
-            LEFT => DOWN,
-            DOWN => RIGHT,
-            RIGHT => UP
-        }
-    }
-
-    fn is_horizontal(&self) -> bool {
-        self == &LEFT || self == &RIGHT
-    }
-
-    fn is_vertical(&self) -> bool {
-        !self.is_horizontal()
-    }
-}
-
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 struct Beam {
-    position: (usize, usize),
-    direction: Direction
+    position: Point<usize>,
+    direction: Direction4
 }
 
 impl Default for Beam {
     fn default() -> Self {
-        Self { position: (0, 0), direction: RIGHT }
+        Self { position: Point::new(0, 0), direction: RIGHT }
     }
 }
 
 impl Beam {
-    fn can_continue(&self, w: usize, h: usize) -> bool {
-        !((self.position.0 == 0 && self.direction == LEFT) ||
-        (self.position.0 == w - 1 && self.direction == RIGHT) ||
-        (self.position.1 == 0 && self.direction == UP) ||
-        (self.position.1 == h - 1 && self.direction == DOWN))
-    }
-
-    fn step(&mut self) {
-        match self.direction {
-            LEFT => self.position.0 -= 1,
-            RIGHT => self.position.0 += 1,
-            UP => self.position.1 -= 1,
-            DOWN => self.position.1 += 1
+    fn step<T>(&mut self, map: &Grid<T>) -> bool {
+        match map.offset(self.position, self.direction) {
+            Some(position) => {
+                self.position = position;
+                true
+            },
+            None => false
         }
     }
 
     fn mirror(&mut self, mirror: char) {
         if (mirror == '/' && self.direction.is_vertical()) || (mirror == '\\' && self.direction.is_horizontal()) {
-            self.direction = self.direction.next();
+            self.direction = self.direction.turn_right();
         } else {
-            self.direction = self.direction.prev();
+            self.direction = self.direction.turn_left();
         }
     }
 
@@ -110,8 +83,8 @@ impl Beam {
             None
         } else {
             Some(vec![
-                Beam { position: self.position, direction: self.direction.next() },
-                Beam { position: self.position, direction: self.direction.prev() }
+                Beam { position: self.position, direction: self.direction.turn_right() },
+                Beam { position: self.position, direction: self.direction.turn_left() }
             ])
         }
     }
@@ -119,18 +92,16 @@ impl Beam {
 
 #[derive(Debug)]
 struct Contraption {
-    map: Vec<Vec<char>>
+    map: Grid<char>
 }
 
 impl Contraption {
     fn simulate_beam(&self, beam: Beam) -> usize {
-        let w = self.map[0].len();
-        let h = self.map.len();
         let mut energized_tiles = HashSet::new();
         let mut visited_states: HashSet<Beam> = HashSet::new();
         let mut beam = beam;
 
-        let c = self.map[beam.position.1][beam.position.0];
+        let c = self.map[beam.position];
 
         if c == '/' || c == '\\' {
             beam.mirror(c);
@@ -145,12 +116,8 @@ impl Contraption {
         energized_tiles.insert(beam.position);
         visited_states.insert(beam);
 
-        while !beams.is_empty() {
-            let mut beam = beams.pop().unwrap();
-
-            while beam.can_continue(w, h) {
-                beam.step();
-
+        while let Some(mut beam) = beams.pop() {
+            while beam.step(&self.map) {
                 if visited_states.contains(&beam) {
                     break;
                 }
@@ -158,7 +125,7 @@ impl Contraption {
                 energized_tiles.insert(beam.position);
                 visited_states.insert(beam);
 
-                let c = self.map[beam.position.1][beam.position.0];
+                let c = self.map[beam.position];
                 if match c {
                     '/' | '\\' => {
                         beam.mirror(c);
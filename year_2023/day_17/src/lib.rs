@@ -0,0 +1,226 @@
+use common::geometry::{Direction4, Point};
+use common::grid::Grid;
+use common::search::{self, Path};
+use common::{Answer, Part, Solution, Style};
+
+common::register!(Day17, year: 2023, day: 17, title: "Clumsy Crucible");
+
+pub struct Day17 {
+    map: Grid<usize>
+}
+
+impl Solution for Day17 {
+    fn parse(input: &str) -> Self {
+        Day17 { map: parse_input(input) }
+    }
+
+    fn part1(&self) -> Answer {
+        part1(&self.map).into()
+    }
+
+    fn part2(&self) -> Answer {
+        part2(&self.map).into()
+    }
+
+    fn visualize(&self, part: Part, style: Style) -> Option<String> {
+        let rules = match part {
+            Part::One => Rules::CRUCIBLE,
+            Part::Two => Rules::ULTRA_CRUCIBLE
+        };
+
+        lava_pool_route(&self.map, rules).map(|path| render_path(&self.map, &path.states, style))
+    }
+}
+
+pub fn parse_input(input: &str) -> Grid<usize> {
+    input.parse::<Grid<char>>().expect("Couldn't parse input").map(|&c| (c as u8 - b'0') as usize)
+}
+
+fn part1(map: &Grid<usize>) -> usize {
+    lava_pool_route(map, Rules::CRUCIBLE).expect("Path for part 1 doesn't exist").cost
+}
+
+fn part2(map: &Grid<usize>) -> usize {
+    lava_pool_route(map, Rules::ULTRA_CRUCIBLE).expect("Path for part 2 doesn't exist").cost
+}
+
+// the puzzle's route, from the top-left to the bottom-right block
+fn lava_pool_route(map: &Grid<usize>, rules: Rules) -> Option<Path<Node, usize>> {
+    route(map, Point::new(0, 0), Point::new(map.width() - 1, map.height() - 1), rules)
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Rules {
+    // blocks to move in a direction before turning or stopping
+    pub min_straight: usize,
+    // None to move in a direction for as long as the map allows
+    pub max_straight: Option<usize>,
+    pub allow_reverse: bool,
+    // extra heat lost for every block moved in each direction, indexed by `Direction4 as usize`
+    pub direction_costs: [usize; 4]
+}
+
+impl Rules {
+    pub const CRUCIBLE: Rules = Rules { min_straight: 1, max_straight: Some(3), allow_reverse: false, direction_costs: [0; 4] };
+    pub const ULTRA_CRUCIBLE: Rules = Rules { min_straight: 4, max_straight: Some(10), allow_reverse: false, direction_costs: [0; 4] };
+
+    fn cost(&self, dir: Direction4) -> usize {
+        self.direction_costs[dir as usize]
+    }
+}
+
+// every node but the start is where a straight run ends, and `direction` is the way that run went
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Node {
+    pub position: Point<usize>,
+    pub direction: Option<Direction4>
+}
+
+pub fn route(map: &Grid<usize>, start: Point<usize>, goal: Point<usize>, rules: Rules) -> Option<Path<Node, usize>> {
+    if !map.contains(start) || !map.contains(goal) {
+        return None;
+    }
+
+    let max_straight = rules.max_straight.unwrap_or(map.width().max(map.height()));
+
+    let successors = |node: &Node| {
+        let mut next = Vec::new();
+
+        for dir in Direction4::ALL {
+            let turns = match node.direction {
+                None => true,
+                Some(last) => last != dir && (rules.allow_reverse || last != dir.opposite())
+            };
+
+            if !turns {
+                continue;
+            }
+
+            let mut delta = 0;
+            let mut position = node.position;
+            for i in 1..max_straight + 1 {
+                let Some(step) = map.offset(position, dir) else {
+                    break;
+                };
+
+                position = step;
+                delta += map[position] + rules.cost(dir);
+
+                if i >= rules.min_straight {
+                    next.push((Node { position, direction: Some(dir) }, delta));
+                }
+            }
+        }
+
+        next
+    };
+
+    // no block can be entered for less than this, so it never overestimates what's left
+    let min_step = map.iter().map(|(_, &heat)| heat).min().unwrap_or(0) + rules.direction_costs.iter().min().unwrap();
+
+    let start = Node { position: start, direction: None };
+
+    search::astar([start], successors, |node| node.position == goal, |node| node.position.manhattan(goal) * min_step)
+}
+
+// overlays the path on the heat map, marking every block it enters with the direction the crucible was moving in
+pub fn render_path(map: &Grid<usize>, path: &[Node], style: Style) -> String {
+    let mut picture = map.map(|heat| heat.to_string());
+
+    for pair in path.windows(2) {
+        let (from, to) = (pair[0].position, pair[1]);
+        let dir = to.direction.expect("Only the start has no direction");
+
+        let mut position = from;
+        while position != to.position {
+            position = map.offset(position, dir).expect("Path leaves the map");
+            picture[position] = match style {
+                Style::Plain => arrow(dir).to_string(),
+                Style::Ansi => format!("\x1b[1;31m{}\x1b[0m", arrow(dir))
+            };
+        }
+    }
+
+    picture.to_string()
+}
+
+fn arrow(dir: Direction4) -> char {
+    match dir {
+        Direction4::UP => '^',
+        Direction4::RIGHT => '>',
+        Direction4::DOWN => 'v',
+        Direction4::LEFT => '<'
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn example_part1() {
+        let map = parse_input(EXAMPLE_INPUT);
+
+        assert_eq!(part1(&map), 102);
+    }
+
+    #[test]
+    fn example_part2() {
+        let map = parse_input(EXAMPLE_INPUT);
+
+        assert_eq!(part2(&map), 94);
+    }
+
+    #[test]
+    fn custom_routes() {
+        let map = parse_input(EXAMPLE_INPUT);
+        let corridor = parse_input("11111\n99999");
+        let unlimited = Rules { max_straight: None, ..Rules::CRUCIBLE };
+        let reversing = Rules { min_straight: 2, allow_reverse: true, ..Rules::CRUCIBLE };
+
+        assert_eq!(route(&map, Point::new(0, 0), Point::new(12, 12), Rules::CRUCIBLE).map(|path| path.cost), Some(102));
+        assert_eq!(route(&map, Point::new(3, 3), Point::new(3, 3), Rules::CRUCIBLE).map(|path| path.cost), Some(0));
+        assert_eq!(route(&map, Point::new(0, 0), Point::new(13, 0), Rules::CRUCIBLE), None);
+        assert_eq!(route(&corridor, Point::new(0, 0), Point::new(4, 0), Rules::CRUCIBLE).map(|path| path.cost), Some(22));
+        assert_eq!(route(&corridor, Point::new(0, 0), Point::new(4, 0), unlimited).map(|path| path.cost), Some(4));
+        assert_eq!(route(&corridor, Point::new(1, 0), Point::new(0, 0), reversing).map(|path| path.cost), Some(5));
+        assert_eq!(route(&corridor, Point::new(1, 0), Point::new(0, 0), Rules { allow_reverse: false, ..reversing }), None);
+        assert_eq!(route(&corridor, Point::new(0, 0), Point::new(4, 0), Rules { direction_costs: [0, 2, 0, 0], ..unlimited }).map(|path| path.cost), Some(12));
+    }
+
+    #[test]
+    fn example_render() {
+        let day = Day17::parse(EXAMPLE_INPUT);
+
+        assert_eq!(day.visualize(Part::One, Style::Plain).unwrap(), EXAMPLE_PATH);
+        assert!(day.visualize(Part::Two, Style::Ansi).unwrap().contains("\x1b[1;31m>\x1b[0m"));
+    }
+
+    const EXAMPLE_INPUT: &str = "2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533";
+
+    const EXAMPLE_PATH: &str = "2>>34^>>>1323
+32v>>>35v5623
+32552456v>>54
+3446585845v52
+4546657867v>6
+14385987984v4
+44578769877v6
+36378779796v>
+465496798688v
+456467998645v
+12246868655<v
+25465488877v5
+43226746555v>";
+}
\ No newline at end of file
@@ -1,90 +1,75 @@
-#![feature(iter_map_windows)]
-
 use std::str::FromStr;
 
 use anyhow::Error;
+use common::geometry::{Direction4::{self, *}, Point};
+use common::iter::IteratorExt;
+use common::{Answer, Solution};
+
+common::register!(Day18, year: 2023, day: 18, title: "Lavaduct Lagoon");
+
+pub struct Day18 {
+    plan: Vec<PlanPart>
+}
+
+impl Solution for Day18 {
+    fn parse(input: &str) -> Self {
+        Day18 { plan: parse_input(input) }
+    }
 
-pub fn solve(input: String) {
-    let plan = parse_input(&input);
+    fn part1(&self) -> Answer {
+        part1(&self.plan).into()
+    }
 
-    println!("{}", part1(&plan));
-    println!("{}", part2(&plan));
+    fn part2(&self) -> Answer {
+        part2(&self.plan).into()
+    }
 }
 
 fn parse_input(input: &str) -> Vec<PlanPart> {
     input.lines().map(|x| x.parse::<PlanPart>().expect("Invalid input")).collect()
 }
 
-fn part1(plan: &Vec<PlanPart>) -> isize {
+fn part1(plan: &[PlanPart]) -> isize {
     let boundary_cells = plan.iter().map(|p| p.distance).sum::<isize>();
-    let vertices = plan.iter().scan((0, 0), |state, p| {
-        *state = p.direction.move_from(state, p.distance);
+    let vertices = plan.iter().scan(Point::new(0, 0), |state, p| {
+        *state = *state + p.direction.delta() * p.distance;
         Some(*state)
-    }).collect::<Vec<(isize, isize)>>();
+    }).collect::<Vec<Point<isize>>>();
     let vertex_count = vertices.len() as isize;
     let concave_vertices = (vertex_count - 4) / 2;
 
     shoelace(&vertices) + (concave_vertices + (vertex_count - concave_vertices) * 3) / 4 + (boundary_cells - vertex_count) / 2
 }
 
-fn part2(plan: &Vec<PlanPart>) -> isize {
+fn part2(plan: &[PlanPart]) -> isize {
     let boundary_cells = plan.iter().map(|p| p.rgb.distance()).sum::<isize>();
-    let vertices = plan.iter().scan((0, 0), |state, p| {
-        *state = p.rgb.direction().move_from(state, p.rgb.distance());
+    let vertices = plan.iter().scan(Point::new(0, 0), |state, p| {
+        *state = *state + p.rgb.direction().delta() * p.rgb.distance();
         Some(*state)
-    }).collect::<Vec<(isize, isize)>>();
+    }).collect::<Vec<Point<isize>>>();
     let vertex_count = vertices.len() as isize;
     let concave_vertices = (vertex_count - 4) / 2;
 
     shoelace(&vertices) + (concave_vertices + (vertex_count - concave_vertices) * 3) / 4 + (boundary_cells - vertex_count) / 2
 }
 
-fn shoelace(points: &Vec<(isize, isize)>) -> isize {
+fn shoelace(points: &[Point<isize>]) -> isize {
     (points.iter()
-        .map_windows(|[a, b]| shoelace_part(a, b))
+        .map_windowed(|[a, b]| shoelace_part(a, b))
         .sum::<isize>() + shoelace_part(points.last().unwrap(), &points[0])).abs() / 2
 }
 
-fn shoelace_part((x1, y1): &(isize, isize), (x2, y2): &(isize, isize)) -> isize {
-    (y1 + y2) * (x2 - x1)
-}
-
-#[derive(Debug, Eq, PartialEq)]
-enum Direction {
-    UP,
-    DOWN,
-    LEFT,
-    RIGHT
+fn shoelace_part(a: &Point<isize>, b: &Point<isize>) -> isize {
+    (a.y + b.y) * (b.x - a.x)
 }
 
-use Direction::*;
-
-impl Direction {
-    fn move_from(&self, (x, y): &(isize, isize), distance: isize) -> (isize, isize) {
-        match self {
-            UP => (*x, y - distance),
-            DOWN => (*x, y + distance),
-            LEFT => (x - distance, *y),
-            RIGHT => (x + distance, *y)
-        }
-    }
-}
-
-impl FromStr for Direction {
-    type Err = Error;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s.len() == 1 {
-            match s.chars().nth(0).unwrap() {
-                'U' => Ok(UP),
-                'D' => Ok(DOWN),
-                'L' => Ok(LEFT),
-                'R' => Ok(RIGHT),
-                _ => Err(Error::msg(format!("`{}` is not a valid direction", s)))
-            }
-        } else {
-            Err(Error::msg(format!("`{}` is not a valid direction", s)))
-        }
+fn parse_direction(s: &str) -> Result<Direction4, Error> {
+    match s {
+        "U" => Ok(UP),
+        "D" => Ok(DOWN),
+        "L" => Ok(LEFT),
+        "R" => Ok(RIGHT),
+        _ => Err(Error::msg(format!("`{}` is not a valid direction", s)))
     }
 }
 
@@ -100,7 +85,7 @@ impl Colour {
         ((self.r * 256 * 256 + self.g * 256 + self.b) / 16) as isize
     }
     
-    fn direction(&self) -> Direction {
+    fn direction(&self) -> Direction4 {
         match self.b % 16 {
             0 => RIGHT,
             1 => DOWN,
@@ -115,13 +100,13 @@ impl FromStr for Colour {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s.len() != 9 || &s[..2] != "(#" || s.chars().last().unwrap() != ')' {
+        if s.len() != 9 || &s[..2] != "(#" || !s.ends_with(')') {
             return Err(Error::msg("Invalid colour format"));
         }
 
         let val = s[2..8].chars()
             .map(|c| c.to_digit(16).ok_or(Error::msg("Invalid hexadecimal digit")))
-            .fold(Ok(0), |acc, d| acc.map(|a| a * 16).and_then(|a| d.map(|b| a + b)))? as usize;
+            .try_fold(0, |acc, d| d.map(|b| acc * 16 + b))? as usize;
         let r = val / (256 * 256);
         let g = val / 256 % 256;
         let b = val % 256;
@@ -132,7 +117,7 @@ impl FromStr for Colour {
 
 #[derive(Debug)]
 struct PlanPart {
-    direction: Direction,
+    direction: Direction4,
     distance: isize,
     rgb: Colour
 }
@@ -143,7 +128,7 @@ impl FromStr for PlanPart {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let [a, b, c] = s.split(" ").collect::<Vec<&str>>().try_into().map_err(|_| Error::msg("Invalid plan format"))?;
 
-        let direction = a.parse::<Direction>()?;
+        let direction = parse_direction(a)?;
         let distance = b.parse::<isize>()?;
         let rgb = c.parse::<Colour>()?;
 
@@ -1,12 +1,29 @@
 use std::{collections::HashMap, str::FromStr};
 
 use anyhow::Error;
+use common::{Answer, Solution};
 
-pub fn solve(input: String) {
-    let (mut system, parts) = parse_input(&input);
+common::register!(Day19, year: 2023, day: 19, title: "Aplenty");
 
-    println!("{}", part1(&system, &parts));
-    println!("{}", part2(&mut system));
+pub struct Day19 {
+    system: System,
+    parts: Vec<Part>
+}
+
+impl Solution for Day19 {
+    fn parse(input: &str) -> Self {
+        let (system, parts) = parse_input(input);
+
+        Day19 { system, parts }
+    }
+
+    fn part1(&self) -> Answer {
+        part1(&self.system, &self.parts).into()
+    }
+
+    fn part2(&self) -> Answer {
+        part2(&mut self.system.clone()).into()
+    }
 }
 
 fn parse_input(input: &str) -> (System, Vec<Part>) {
@@ -18,8 +35,8 @@ fn parse_input(input: &str) -> (System, Vec<Part>) {
     (system, parts)
 }
 
-fn part1(system: &System, parts: &Vec<Part>) -> usize {
-    parts.iter().filter(|p| system.is_accepted(*p)).map(|p| p.value()).sum()
+fn part1(system: &System, parts: &[Part]) -> usize {
+    parts.iter().filter(|p| system.is_accepted(p)).map(|p| p.value()).sum()
 }
 
 fn part2(system: &mut System) -> usize {
@@ -263,7 +280,7 @@ impl System {
     }
 
     fn eval_workflow(&mut self, name: &str) -> &Vec<PartRange> {
-        if self.evaluations.get(name).is_none() {
+        if !self.evaluations.contains_key(name) {
             let workflow = self.workflows.get(name).unwrap().clone();
             let new_evaluation = workflow.eval_range(self);
             self.evaluations.insert(name.to_owned(), new_evaluation);
@@ -1,14 +1,28 @@
-#![feature(iter_intersperse)]
-
 use std::{collections::{HashMap, VecDeque}, ops::Neg, str::FromStr};
 
 use anyhow::Error;
+use common::iter::IteratorExt;
+use common::math::lcm;
+use common::{Answer, Solution};
+
+common::register!(Day20, year: 2023, day: 20, title: "Pulse Propagation");
+
+pub struct Day20 {
+    circuit: Circuit
+}
 
-pub fn solve(input: String) {
-    let mut circuit = parse_input(&input);
+impl Solution for Day20 {
+    fn parse(input: &str) -> Self {
+        Day20 { circuit: parse_input(input) }
+    }
+
+    fn part1(&self) -> Answer {
+        part1(&mut self.circuit.clone()).into()
+    }
 
-    println!("{}", part1(&mut circuit));
-    println!("{}", part2(&circuit));
+    fn part2(&self) -> Answer {
+        part2(&self.circuit).into()
+    }
 }
 
 fn parse_input(input: &str) -> Circuit {
@@ -31,14 +45,14 @@ fn part1(circuit: &mut Circuit) -> usize {
 fn part2(circuit: &Circuit) -> usize {
     // Draw a graph of the input. The whole circuit is just 4 12-bit binary counters connected to a conjunction at the end, which only sends
     // a 0 to rx if all of the counters reach 0 at the same time. Find the period of each counter, the answer is LCM of those periods.
-    circuit.outputs["broadcaster"].iter().map(|cs| max_counter_value(circuit, &cs, 1, 1))
+    circuit.outputs["broadcaster"].iter().map(|cs| max_counter_value(circuit, cs, 1, 1))
         .reduce(lcm)
         .unwrap()
 }
 
 fn max_counter_value(circuit: &Circuit, node: &str, acc: usize, i: usize) -> usize {
     let next_node = circuit.outputs[node].iter()
-        .find(|name| if let ModuleType::Conjunction(_) = circuit.modules[*name].module_type { false } else { true } );
+        .find(|name| !matches!(circuit.modules[*name].module_type, ModuleType::Conjunction(_)));
     
     
     if let Some(next_node) = next_node {
@@ -49,24 +63,6 @@ fn max_counter_value(circuit: &Circuit, node: &str, acc: usize, i: usize) -> usi
     }
 }
 
-fn lcm(a: usize, b: usize) -> usize {
-    (a * b) / gcd(a, b)
-}
-
-fn gcd(a: usize, b: usize) -> usize {
-    let (a, b) = if a < b {
-        (b, a)
-    } else {
-        (a, b)
-    };
-
-    if b == 0 {
-        a
-    } else {
-        gcd(b, a % b)
-    }
-}
-
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 enum Pulse {
     Low,
@@ -134,6 +130,9 @@ impl Module {
     }
 }
 
+// the wiring is directed and a conjunction remembers its inputs by position, so it's kept in
+// its own maps rather than in the undirected common::graph::Graph
+#[derive(Clone)]
 struct Circuit {
     modules: HashMap<String, Module>,
     outputs: HashMap<String, Vec<String>>,
@@ -174,9 +173,9 @@ impl Circuit {
                 ModuleType::Conjunction(_) => format!("{name}{{{name}}}"),
                 ModuleType::FlipFlop(_) => format!("{name}({name})")
             } + "\n";
-            let connections: String = self.outputs.get(name).map(|x| x.iter().map(|t| format!("\t{name} --> {t}")).intersperse("\n\t".to_owned()).collect()).unwrap_or("".to_owned());
+            let connections: String = self.outputs.get(name).map(|x| x.iter().map(|t| format!("\t{name} --> {t}")).interspersed("\n\t".to_owned()).collect()).unwrap_or("".to_owned());
             def + &connections
-        }).intersperse("\n".to_owned()).collect::<String>().as_ref()
+        }).interspersed("\n".to_owned()).collect::<String>().as_ref()
     }
 }
 
@@ -209,7 +208,7 @@ impl FromStr for Circuit {
             modules.insert(source.name.clone(), source);
         }
 
-        for module in modules.values_mut().filter(|x| if let ModuleType::Conjunction(_) = x.module_type { true } else { false }) {
+        for module in modules.values_mut().filter(|x| matches!(x.module_type, ModuleType::Conjunction(_))) {
             let ModuleType::Conjunction(v) = &mut module.module_type else { panic!("will never happen") };
             for _ in 0..inputs.get(&module.name).unwrap().len() {
                 v.push(Pulse::Low);
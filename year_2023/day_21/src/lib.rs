@@ -0,0 +1,116 @@
+use common::geometry::{Direction4, Point};
+use common::grid::Grid;
+use common::rational::Rational;
+use common::search;
+use common::{Answer, Solution};
+use matrix::Matrix;
+use vector::Vector;
+
+mod matrix;
+mod vector;
+
+common::register!(Day21, year: 2023, day: 21, title: "Step Counter");
+
+pub struct Day21 {
+    map: Grid<char>,
+    starting_pos: Point<isize>
+}
+
+impl Solution for Day21 {
+    fn parse(input: &str) -> Self {
+        let (map, starting_pos) = parse_input(input);
+
+        Day21 { map, starting_pos }
+    }
+
+    fn part1(&self) -> Answer {
+        part1(&self.map, self.starting_pos).into()
+    }
+
+    fn part2(&self) -> Answer {
+        part2(&self.map, self.starting_pos).into()
+    }
+}
+
+fn parse_input(input: &str) -> (Grid<char>, Point<isize>) {
+    let map: Grid<char> = input.parse().expect("Couldn't parse input");
+    let start = map.position(|c| c == &'S').expect("No starting position");
+
+    (map, Point::new(start.x as isize, start.y as isize))
+}
+
+fn part1(map: &Grid<char>, starting_pos: Point<isize>) -> usize {
+    bfs(map, starting_pos, 64)
+}
+
+fn part2(map: &Grid<char>, starting_pos: Point<isize>) -> usize {
+    let dim = map.height();
+    let steps = 26501365;
+    let x = steps % dim;
+
+    let x0 = x;
+    let x1 = x + dim;
+    let x2 = x + 2 * dim;
+
+    let y0 = (bfs(map, starting_pos, x0) as i128).into();
+    let y1 = (bfs(map, starting_pos, x1) as i128).into();
+    let y2 = (bfs(map, starting_pos, x2) as i128).into();
+
+    let x0 = (x0 as i128).into();
+    let x1 = (x1 as i128).into();
+    let x2 = (x2 as i128).into();
+
+    let m = Matrix([
+        [x0 * x0, x0, Rational::one()],
+        [x1 * x1, x1, Rational::one()],
+        [x2 * x2, x2, Rational::one()]
+    ]);
+    let ys = Vector([y0, y1, y2]);
+
+    let [a, b, c] = (m.inv().unwrap() * ys).0;
+
+    let steps: Rational<i128> = (steps as i128).into();
+    let result = a * steps * steps + b * steps + c;
+
+    result.to_integer().expect("the step count should be a whole number") as usize
+}
+
+fn bfs(map: &Grid<char>, starting_pos: Point<isize>, max_distance: usize) -> usize {
+    let (w, h) = (map.width() as isize, map.height() as isize);
+    let tile = |p: Point<isize>| map[Point::new(p.x.rem_euclid(w) as usize, p.y.rem_euclid(h) as usize)];
+
+    let successors = |&position: &Point<isize>| Direction4::ALL.into_iter()
+        .map(|direction| position + direction.delta())
+        .filter(|&next| tile(next) != '#')
+        .map(|next| (next, 1))
+        .collect();
+
+    search::distances_within([starting_pos], successors, max_distance)
+        .into_values()
+        .filter(|distance| distance % 2 == max_distance % 2)
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn example_part1() {
+        let (map, starting_pos) = parse_input(EXAMPLE_INPUT);
+
+        assert_eq!(bfs(&map, starting_pos, 6), 16);
+    }
+
+    const EXAMPLE_INPUT: &str = "...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........";
+}
\ No newline at end of file
@@ -1,13 +1,14 @@
 use std::ops::Mul;
 
-use crate::fraction::Fraction;
+use common::rational::Rational;
+
 use crate::vector::Vector;
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
-pub struct Matrix(pub [[Fraction; 3]; 3]);
+pub struct Matrix(pub [[Rational<i128>; 3]; 3]);
 
 impl Matrix {
-    fn det(&self) -> Fraction {
+    fn det(&self) -> Rational<i128> {
         let mat = self.0;
 
         mat[0][0] * (mat[1][1] * mat[2][2] - mat[2][1] * mat[1][2])
@@ -41,12 +42,12 @@ impl Matrix {
         if det == 0.into() {
             None
         } else {
-            Some(det.inv() * self.adjoint())
+            Some(det.recip() * self.adjoint())
         }
     }
 }
 
-impl Mul<Matrix> for Fraction {
+impl Mul<Matrix> for Rational<i128> {
     type Output = Matrix;
 
     fn mul(self, rhs: Matrix) -> Self::Output {
@@ -67,7 +68,7 @@ impl Mul<Vector> for Matrix {
 
     fn mul(self, rhs: Vector) -> Self::Output {
         Vector(self.0.into_iter()
-            .map(|row| row.into_iter().zip(rhs.0.into_iter()).map(|(a, b)| a * b).sum())
+            .map(|row| row.into_iter().zip(rhs.0).map(|(a, b)| a * b).sum())
             .collect::<Vec<_>>()
             .try_into()
             .unwrap())
@@ -76,7 +77,6 @@ impl Mul<Vector> for Matrix {
 
 #[cfg(test)]
 mod tests {
-    use crate::fraction::Fraction;
     use crate::matrix::*;
     use crate::vector::*;
 
@@ -91,8 +91,8 @@ mod tests {
     fn test_inv() {
         let m = Matrix([[4.into(), 2.into(), 1.into()], [9.into(), 3.into(), 1.into()], [16.into(), 4.into(), 1.into()]]);
         let expected = Matrix([
-            [Fraction::new(1, 2), (-1).into(), Fraction::new(1, 2)],
-            [Fraction::new(-7, 2), 6.into(), Fraction::new(-5, 2)],
+            [Rational::new(1, 2), (-1).into(), Rational::new(1, 2)],
+            [Rational::new(-7, 2), 6.into(), Rational::new(-5, 2)],
             [6.into(), (-8).into(), 3.into()]
         ]);
 
@@ -0,0 +1,4 @@
+use common::rational::Rational;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Vector(pub [Rational<i128>; 3]);
\ No newline at end of file
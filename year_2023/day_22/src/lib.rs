@@ -1,14 +1,26 @@
 use std::{str::FromStr, iter::repeat, collections::HashSet};
 
 use anyhow::Error;
+use common::{Answer, Solution};
 
-pub fn solve(input: String) {
-    let bricks = parse_input(&input);
+common::register!(Day22, year: 2023, day: 22, title: "Sand Slabs");
 
-    let result = settle(bricks);
+pub struct Day22 {
+    bricks: Vec<Brick>
+}
+
+impl Solution for Day22 {
+    fn parse(input: &str) -> Self {
+        Day22 { bricks: parse_input(input) }
+    }
 
-    println!("{}", result.0);
-    println!("{}", result.1);
+    fn part1(&self) -> Answer {
+        settle(self.bricks.clone()).0.into()
+    }
+
+    fn part2(&self) -> Answer {
+        settle(self.bricks.clone()).1.into()
+    }
 }
 
 fn parse_input(input: &str) -> Vec<Brick> {
@@ -38,15 +50,15 @@ fn settle(mut bricks: Vec<Brick>) -> (usize, usize) {
 
         let mut supporters = HashSet::new();
 
-        for y in y1..y2 + 1 {
-            for x in x1..x2 + 1 {
-                if let Some(idx) = brickmap[y][x] {
+        for row in &mut brickmap[y1..y2 + 1] {
+            for cell in &mut row[x1..x2 + 1] {
+                if let Some(idx) = *cell {
                     if settled[idx].1.z == new_z - 1 {
                         supporters.insert(idx);
                     }
                 }
 
-                brickmap[y][x] = Some(i);
+                *cell = Some(i);
             }
         }
 
@@ -65,8 +77,8 @@ fn settle(mut bricks: Vec<Brick>) -> (usize, usize) {
         let mut deleted = HashSet::new();
         deleted.insert(i);
 
-        for j in i+1..settled.len() {
-            if supported_by[j].len() != 0 && supported_by[j].is_subset(&deleted) {
+        for (j, supporters) in supported_by.iter().enumerate().skip(i + 1) {
+            if !supporters.is_empty() && supporters.is_subset(&deleted) {
                 deleted.insert(j);
             }
         }
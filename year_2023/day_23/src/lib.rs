@@ -0,0 +1,207 @@
+use std::collections::{HashMap, VecDeque, HashSet};
+
+use common::geometry::{Direction4::{self, *}, Point};
+use common::graph;
+use common::grid::Grid;
+use common::{Answer, Solution};
+
+common::register!(Day23, year: 2023, day: 23, title: "A Long Walk");
+
+pub struct Day23 {
+    graph: Graph
+}
+
+impl Solution for Day23 {
+    fn parse(input: &str) -> Self {
+        Day23 { graph: parse_input(input) }
+    }
+
+    fn part1(&self) -> Answer {
+        part1(&self.graph).into()
+    }
+
+    fn part2(&self) -> Answer {
+        part2(&self.graph).into()
+    }
+}
+
+fn parse_input(input: &str) -> Graph {
+    Graph(input.parse().expect("Couldn't parse input"))
+}
+
+fn part1(g: &Graph) -> usize {
+    let mut parents = HashMap::<Point<usize>, Vec<Point<usize>>>::new();
+    let mut visited = HashSet::new();
+    let mut q = VecDeque::new();
+
+    parents.insert(Point::new(1, 0), vec![]);
+    q.push_back((None, Point::new(1, 0)));
+
+    while !q.is_empty() {
+        let (pred, pos) = q.pop_front().unwrap();
+
+        if visited.contains(&pos) {
+            continue;
+        }
+
+        visited.insert(pos);
+
+        for npos in g.neighbours(pos, true) {
+            if pred == Some(npos) {
+                continue;
+            }
+            parents.entry(npos).or_default().push(pos);
+            q.push_back((Some(pos), npos));
+        }
+    }
+
+    max_distance1(Point::new(g.0.width() - 2, g.0.height() - 1), &parents, &mut HashMap::new())
+}
+
+fn max_distance1(pos: Point<usize>, parents: &HashMap<Point<usize>, Vec<Point<usize>>>, dp: &mut HashMap<Point<usize>, usize>) -> usize {
+    if !dp.contains_key(&pos) {
+        let v = parents[&pos].iter()
+            .map(|&parent| max_distance1(parent, parents, dp))
+            .max()
+            .map(|x| x + 1)
+            .unwrap_or(0);
+        dp.insert(pos, v);
+    }
+
+    dp[&pos]
+}
+
+fn part2(g: &Graph) -> usize {
+    let mut junctions = graph::Graph::<Point<usize>, usize>::new();
+    let mut visited = HashSet::new();
+    let mut q = VecDeque::new();
+
+    q.push_back(Extender { source: Point::new(1, 0), prev: Point::new(1, 0), dist: 1, curr: Point::new(1, 1) });
+
+    while !q.is_empty() {
+        let Extender { source, prev, dist, curr } = q.pop_front().unwrap();
+        let neighbours = g.neighbours(curr, false);
+
+        if neighbours.len() == 2 {
+            let next = neighbours.into_iter().rfind(|&n| n != prev).unwrap();
+            q.push_back(Extender { source, prev: curr, dist: dist + 1, curr: next });
+        } else {
+            // every corridor is walked from both of its ends
+            let (from, to) = (junctions.add_node(source), junctions.add_node(curr));
+            if !junctions.neighbours(from).any(|(n, edge)| n == to && junctions.edge(edge).is_some_and(|(_, _, &d)| d == dist)) {
+                junctions.connect(from, to, dist);
+            }
+            if !visited.contains(&curr) {
+                neighbours.into_iter()
+                    .filter(|&n| n != prev)
+                    .for_each(|n| q.push_back(Extender { source: curr, prev: curr, dist: 1, curr: n}));
+                visited.insert(curr);
+            }
+        }
+    }
+
+    let start = junctions.id(&Point::new(1, 0)).unwrap();
+    let end = junctions.id(&Point::new(g.0.width() - 2, g.0.height() - 1)).unwrap();
+
+    max_distance2(start, end, &junctions, &mut vec![false; junctions.node_count()]).unwrap()
+}
+
+fn max_distance2(from: usize, to: usize, junctions: &graph::Graph<Point<usize>, usize>, visited: &mut [bool]) -> Option<usize> {
+    if from == to {
+        return Some(0);
+    }
+
+    visited[from] = true;
+
+    let result = junctions.neighbours(from)
+        .filter_map(|(target, edge)| {
+            if visited[target] {
+                return None;
+            }
+
+            let (_, _, &dist) = junctions.edge(edge)?;
+            max_distance2(target, to, junctions, visited).map(|x| x + dist)
+        })
+        .max();
+
+    visited[from] = false;
+
+    result
+}
+
+struct Extender {
+    source: Point<usize>,
+    prev: Point<usize>,
+    dist: usize,
+    curr: Point<usize>
+}
+
+#[derive(Debug)]
+struct Graph(Grid<char>);
+
+impl Graph {
+    fn next(&self, position: Point<usize>, direction: Direction4, part_1: bool) -> Option<Point<usize>> {
+        let c = match direction {
+            LEFT => '>',
+            RIGHT => '<',
+            UP => 'v',
+            DOWN => '^'
+        };
+        let next = self.0.offset(position, direction)?;
+
+        if self.0[next] != '#' && (!part_1 || self.0[next] != c) {
+            Some(next)
+        } else {
+            None
+        }
+    }
+
+    fn neighbours(&self, position: Point<usize>, part_1: bool) -> Vec<Point<usize>> {
+        [UP, DOWN, LEFT, RIGHT].into_iter()
+            .filter_map(|dir| self.next(position, dir, part_1))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn example_part1() {
+        let g = parse_input(EXAMPLE_INPUT);
+
+        assert_eq!(part1(&g), 94);
+    }
+
+    #[test]
+    fn example_part2() {
+        let g = parse_input(EXAMPLE_INPUT);
+
+        assert_eq!(part2(&g), 154);
+    }
+
+    const EXAMPLE_INPUT: &str = "#.#####################
+#.......#########...###
+#######.#########.#.###
+###.....#.>.>.###.#.###
+###v#####.#v#.###.#.###
+###.>...#.#.#.....#...#
+###v###.#.#.#########.#
+###...#.#.#.......#...#
+#####.#.#.#######.#.###
+#.....#.#.#.......#...#
+#.#####.#.#.#########v#
+#.#...#...#...###...>.#
+#.#.#v#######v###.###v#
+#...#.>.#...>.>.#.###.#
+#####v#.#.###v#.#.###.#
+#.....#...#...#.#.#...#
+#.#########.###.#.#.###
+#...###...#...#...#.###
+###.###.#.###v#####v###
+#...#...#.#.>.>.#.>.###
+#.###.###.#.###.#.#v###
+#.....###...###...#...#
+#####################.#";
+}
\ No newline at end of file
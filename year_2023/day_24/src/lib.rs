@@ -1,11 +1,22 @@
 use std::str::FromStr;
 
 use anyhow::Error;
+use common::{Answer, Solution};
 
-pub fn solve(input: String) {
-    let hailstones = parse_input(&input);
+common::register!(Day24, year: 2023, day: 24, title: "Never Tell Me The Odds");
 
-    println!("{}", part1(&hailstones, (200000000000000.0, 400000000000000.0)));
+pub struct Day24 {
+    hailstones: Vec<Hailstone>
+}
+
+impl Solution for Day24 {
+    fn parse(input: &str) -> Self {
+        Day24 { hailstones: parse_input(input) }
+    }
+
+    fn part1(&self) -> Answer {
+        part1(&self.hailstones, (200000000000000.0, 400000000000000.0)).into()
+    }
 }
 
 fn parse_input(input: &str) -> Vec<Hailstone> {
@@ -15,7 +26,7 @@ fn parse_input(input: &str) -> Vec<Hailstone> {
         .expect("Invalid input")
 }
 
-fn part1(hailstones: &Vec<Hailstone>, (lbound, hbound): (f64, f64)) -> usize {
+fn part1(hailstones: &[Hailstone], (lbound, hbound): (f64, f64)) -> usize {
     hailstones.iter().enumerate()
         .map(|(i, &h)| hailstones.iter()
             .skip(i + 1)
@@ -70,11 +81,11 @@ impl Hailstone {
     }
 }
 
-impl Into<Line2D> for Hailstone {
-    fn into(self) -> Line2D {
-        let a = self.velocity.1 as f64;
-        let b = -self.velocity.0 as f64;
-        let c = a * self.position.0 as f64 + b * self.position.1 as f64;
+impl From<Hailstone> for Line2D {
+    fn from(val: Hailstone) -> Self {
+        let a = val.velocity.1 as f64;
+        let b = -val.velocity.0 as f64;
+        let c = a * val.position.0 as f64 + b * val.position.1 as f64;
 
         Line2D { a, b, c }
     }
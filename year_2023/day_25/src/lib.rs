@@ -0,0 +1,113 @@
+use common::graph::Graph;
+use common::{Answer, Part, Solution, Style};
+
+common::register!(Day25, year: 2023, day: 25, title: "Snowverload");
+
+pub struct Day25 {
+    graph: Graph<String>
+}
+
+impl Solution for Day25 {
+    fn parse(input: &str) -> Self {
+        Day25 { graph: parse_input(input) }
+    }
+
+    fn part1(&self) -> Answer {
+        part1(&self.graph).into()
+    }
+
+    // render with e.g. `dot -Tpng -Kneato`
+    fn visualize(&self, part: Part, _style: Style) -> Option<String> {
+        if part != Part::One {
+            return None;
+        }
+
+        graphviz(&self.graph)
+    }
+}
+
+fn parse_input(input: &str) -> Graph<String> {
+    let mut graph = Graph::new();
+
+    for line in input.lines() {
+        let (from, to) = line.split_once(": ").expect("Invalid line format");
+
+        for v in to.split(" ") {
+            graph.add_edge(from.to_owned(), v.to_owned(), ());
+        }
+    }
+
+    graph
+}
+
+fn part1(graph: &Graph<String>) -> usize {
+    let cut = graph.global_min_cut().expect("The graph can't be cut");
+    let mut graph = graph.clone();
+
+    for edge in cut.edges {
+        graph.remove_edge(edge);
+    }
+
+    let size = graph.component_of(0).len();
+
+    size * (graph.node_count() - size)
+}
+
+// the wiring diagram in Graphviz format, with the cut wires in red
+fn graphviz(graph: &Graph<String>) -> Option<String> {
+    let cut = graph.global_min_cut()?;
+
+    Some(graph.to_dot(|edge, _| cut.edges.contains(&edge).then(|| "color=red".to_owned())))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn example_part1() {
+        let graph = parse_input(EXAMPLE_INPUT);
+
+        assert_eq!(part1(&graph), 54);
+    }
+
+    #[test]
+    fn example_min_cut() {
+        let graph = parse_input(EXAMPLE_INPUT);
+        let mut edges: Vec<_> = graph.global_min_cut().unwrap().edges.into_iter()
+            .map(|edge| {
+                let (u, v, _) = graph.edge(edge).unwrap();
+                let mut edge = [graph.node(u).as_str(), graph.node(v).as_str()];
+                edge.sort();
+                edge
+            })
+            .collect();
+        edges.sort();
+
+        assert_eq!(edges, vec![["bvb", "cmg"], ["hfx", "pzl"], ["jqt", "nvd"]]);
+    }
+
+    #[test]
+    fn example_graphviz() {
+        let day = Day25::parse(EXAMPLE_INPUT);
+        let dot = day.visualize(Part::One, Style::Plain).unwrap();
+
+        assert!(dot.starts_with("graph {\n\tjqt -- rhn;\n"));
+        assert_eq!(dot.matches("[color=red]").count(), 3);
+        assert_eq!(dot.lines().count(), 33 + 2);
+    }
+
+    const EXAMPLE_INPUT: &str = "jqt: rhn xhk nvd
+rsh: frs pzl lsr
+xhk: hfx
+cmg: qnr nvd lhk bvb
+rhn: xhk bvb hfx
+bvb: xhk hfx
+pzl: lsr hfx nvd
+qnr: nvd
+ntq: jqt hfx bvb xhk
+nvd: lhk
+lsr: lhk
+rzs: qnr cmg lsr rsh
+frs: qnr lhk lsr";
+}